@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+fn find_sum(v: &Vec<i32>, sum: i32) -> (i32, i32) {
+    let mut m = HashMap::new();
+    for n in v {
+        let k = sum - *n;
+        match m.get(&k) {
+            Some(_) => {
+                return (k, *n);
+            }
+            None => {
+                m.insert(n, true);
+            }
+        }
+    }
+    (0, 0)
+}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        s.lines().map(|line| number(s, line)).collect()
+    }
+
+    fn part1(v: &Self::Input) -> i32 {
+        let ans = find_sum(v, 2020);
+        ans.0 * ans.1
+    }
+
+    fn part2(v: &Self::Input) -> i32 {
+        for n in v {
+            let sum = 2020 - *n;
+            let ans = find_sum(v, sum);
+            if ans.0 > 0 && ans.1 > 0 {
+                return *n * ans.0 * ans.1;
+            }
+        }
+        0
+    }
+}
+
+impl Generate for Solver {
+    /// `size` entries, among them a pair and a triple summing to 2020.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pair = rng.range(1..=2019) as i32;
+        let first = rng.range(1..=1009) as i32;
+        let second = rng.range(1..=2019 - first as i64 - 1) as i32;
+        let mut entries = vec![pair, 2020 - pair, first, second, 2020 - first - second];
+        while entries.len() < size {
+            entries.push(rng.range(1..=2019) as i32);
+        }
+        rng.shuffle(&mut entries);
+        entries
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
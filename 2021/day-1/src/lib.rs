@@ -0,0 +1,59 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+fn find_increase(sample: &[i32], window: usize) -> i32 {
+    let mut v = vec![];
+    for (i, _) in sample.iter().enumerate() {
+        if i >= window - 1 {
+            let mut sum = 0;
+            for j in 0..window {
+                sum += sample.get(i - j).unwrap();
+            }
+            v.push(sum);
+        }
+    }
+    let mut res = 0;
+    for i in 1..v.len() {
+        if v.get(i) > v.get(i - 1) {
+            res += 1;
+        }
+    }
+    res
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        s.lines().map(|line| number(s, line)).collect()
+    }
+
+    fn part1(v: &Self::Input) -> i32 {
+        find_increase(v, 1)
+    }
+
+    fn part2(v: &Self::Input) -> i32 {
+        find_increase(v, 3)
+    }
+}
+
+impl Generate for Solver {
+    /// `size` depths, mostly going deeper.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut depth = rng.range(100..=200);
+        (0..size)
+            .map(|_| {
+                depth = (depth + rng.range(-10..=20)).max(0);
+                depth.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
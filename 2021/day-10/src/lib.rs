@@ -0,0 +1,160 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{invalid_char, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+enum LinePattern {
+    Incomplete,
+    Corrupted,
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let lines = s
+            .lines()
+            .map(|line| {
+                if let Some(c) = invalid_char(line, |c| "()[]{}<>".contains(c)) {
+                    return Err(ParseError::at(s, c, "expected a bracket"));
+                }
+                Ok(line.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if lines.iter().all(|line| corrupted_score(line) != 0) {
+            return Err(ParseError::end(s, "expected a line that is not corrupted"));
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> i64 {
+        lines.iter().map(|line| corrupted_score(line)).sum()
+    }
+
+    fn part2(lines: &Self::Input) -> i64 {
+        let mut part2_score = vec![];
+        for line in lines {
+            if corrupted_score(line) == 0 {
+                part2_score.push(incomplete_score(line));
+            }
+        }
+        part2_score.sort_unstable();
+        *part2_score.get(part2_score.len() / 2).unwrap()
+    }
+}
+
+fn incomplete_score(line: &str) -> i64 {
+    let mut stack: Vec<char> = vec![];
+    for c in line.chars() {
+        if is_open(c) {
+            stack.push(c);
+            continue;
+        }
+        let open_char = stack.pop().unwrap();
+        if get_close(open_char) != c {
+            panic!("input line ({}) is corrupted", line);
+        }
+    }
+    stack.reverse();
+    let mut score = 0;
+    for c in stack {
+        score = score * 5 + ch_score(get_close(c), LinePattern::Incomplete);
+    }
+    score
+}
+
+fn corrupted_score(s: &str) -> i64 {
+    let mut stack: Vec<char> = vec![];
+    for c in s.chars() {
+        if is_open(c) {
+            stack.push(c);
+            continue;
+        }
+        if let Some(v) = stack.last() {
+            if get_close(*v) == c {
+                stack.pop();
+            } else {
+                return ch_score(c, LinePattern::Corrupted);
+            }
+        } else {
+            return ch_score(c, LinePattern::Corrupted);
+        }
+    }
+    0
+}
+
+fn get_close(c: char) -> char {
+    match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!("{} not valid", c),
+    }
+}
+
+fn ch_score(c: char, p: LinePattern) -> i64 {
+    match p {
+        LinePattern::Incomplete => match c {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => unreachable!("{} not valid", c),
+        },
+        LinePattern::Corrupted => match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => unreachable!("{} not valid", c),
+        },
+    }
+}
+
+fn is_open(c: char) -> bool {
+    c == '(' || c == '[' || c == '{' || c == '<'
+}
+
+impl Generate for Solver {
+    /// `size` lines of about a hundred brackets, some corrupted and the rest
+    /// (at least the first) incomplete.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const OPEN: [char; 4] = ['(', '[', '{', '<'];
+        (0..size.max(1))
+            .map(|i| {
+                let corrupted = i > 0 && rng.chance(0.5);
+                let mut line = String::new();
+                let mut stack = vec![];
+                for _ in 0..rng.range(20..=100) {
+                    // nested no deeper than 20 so that completion scores fit
+                    if stack.is_empty() || (stack.len() < 20 && rng.chance(0.55)) {
+                        let open = *rng.pick(&OPEN);
+                        stack.push(open);
+                        line.push(open);
+                    } else {
+                        line.push(get_close(stack.pop().unwrap()));
+                    }
+                }
+                if corrupted {
+                    let open = *rng.pick(&OPEN);
+                    line.push(open);
+                    let wrong = OPEN.iter().filter(|&&c| c != open).collect::<Vec<_>>();
+                    line.push(get_close(**rng.pick(&wrong)));
+                } else if stack.is_empty() {
+                    line.push(*rng.pick(&OPEN));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
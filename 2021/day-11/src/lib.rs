@@ -0,0 +1,257 @@
+use aoc_common::{
+    cycle,
+    frames::{Frame, Recorder, Visualise},
+    generate::{Generate, Rng},
+    grid::{Grid, Pos},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Octopus {
+    energy: i32,
+    flashed: bool,
+}
+
+impl Octopus {
+    fn new(energy: i32) -> Self {
+        Self {
+            energy,
+            flashed: false,
+        }
+    }
+}
+
+impl std::fmt::Display for Octopus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.flashed {
+            write!(f, "[{}]", self.energy)
+        } else {
+            write!(f, " {} ", self.energy)
+        }
+    }
+}
+
+pub type EnergyMap = Grid<Octopus>;
+
+struct OctopusMap {
+    map: EnergyMap,
+    total_flash_cnt: i32,
+    one_step_flash_cnt: i32,
+    step_cnt: i32,
+}
+
+impl OctopusMap {
+    fn new(map: EnergyMap) -> Self {
+        Self {
+            map,
+            total_flash_cnt: 0,
+            one_step_flash_cnt: 0,
+            step_cnt: 0,
+        }
+    }
+    /// Octopuses that just flashed drawn as `#`, the others as their energy.
+    fn frame(&self) -> Frame {
+        self.map.map(|octopus| {
+            if octopus.flashed {
+                '#'
+            } else {
+                char::from_digit(octopus.energy as u32, 10).unwrap_or('?')
+            }
+        })
+    }
+    fn flash_once(&mut self) {
+        self.total_flash_cnt += 1;
+        self.one_step_flash_cnt += 1;
+    }
+    fn is_all_flash(&self) -> bool {
+        self.one_step_flash_cnt as usize == self.map.cells().len()
+    }
+    fn step(&mut self) {
+        self.step_cnt += 1;
+        for pos in self.map.positions() {
+            let octopus = &mut self.map[pos];
+            if !octopus.flashed {
+                octopus.energy += 1;
+                if octopus.energy > 9 {
+                    self.flash(pos);
+                }
+            }
+        }
+    }
+    fn clean_step(&mut self) {
+        self.one_step_flash_cnt = 0;
+        for (_, octopus) in self.map.iter_mut() {
+            octopus.flashed = false;
+        }
+    }
+    fn flash(&mut self, pos: Pos) {
+        if self.map[pos].flashed {
+            return;
+        }
+        self.flash_once();
+        self.map[pos].energy = 0;
+        self.map[pos].flashed = true;
+
+        let neighbors = self.map.neighbors8(pos).collect::<Vec<_>>();
+        for n in neighbors {
+            self.energy_spread(n);
+        }
+    }
+
+    fn energy_spread(&mut self, pos: Pos) {
+        let octopus = &mut self.map[pos];
+        if !octopus.flashed {
+            octopus.energy += 1;
+            if octopus.energy > 9 {
+                self.flash(pos);
+            }
+        }
+    }
+}
+
+/// The energy levels one step after `energy`.
+fn step(energy: &EnergyMap) -> EnergyMap {
+    let mut map = OctopusMap::new(energy.clone());
+    map.step();
+    map.clean_step();
+    map.map
+}
+
+/// The first step on which every octopus flashes at once, or `None` when they
+/// go round a cycle in which that never happens.
+fn synchronise(energy: &EnergyMap, recorder: &mut Recorder) -> Option<u64> {
+    recorder.record(|| OctopusMap::new(energy.clone()).frame());
+    let watched = |energy: &EnergyMap| {
+        let mut map = OctopusMap::new(energy.clone());
+        map.step();
+        recorder.record(|| map.frame());
+        map.clean_step();
+        map.map
+    };
+    // having just flashed, every one of them is back at zero
+    let all_flashed = |energy: &EnergyMap| energy.cells().iter().all(|o| o.energy == 0);
+    cycle::until(energy.clone(), watched, all_flashed).map(|(steps, _)| steps)
+}
+
+/// Flashes in the first `n` steps, however many: the octopuses end up
+/// flashing in a cycle, walked round once and multiplied.
+pub fn flashes_after(energy: &EnergyMap, n: u64) -> u64 {
+    let cycle = cycle::find(energy, step, n);
+    let steps = cycle.map_or(n, |c| (c.offset + c.period) as u64);
+    let mut map = OctopusMap::new(energy.clone());
+    let mut totals = vec![0];
+    for _ in 0..steps {
+        map.step();
+        map.clean_step();
+        totals.push(map.total_flash_cnt as u64);
+    }
+    match cycle {
+        Some(cycle) => cycle.total(&totals, n),
+        None => totals[n as usize],
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = EnergyMap;
+    type Answer1 = i32;
+    type Answer2 = u64;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let energy = Grid::parse(s.trim(), |c| c.to_digit(10).map(|n| Octopus::new(n as i32)))?;
+        if synchronise(&energy, &mut Recorder::off()).is_none() {
+            return Err(ParseError::end(
+                s,
+                "expected octopuses that all flash together at some point",
+            ));
+        }
+        Ok(energy)
+    }
+
+    fn part1(energy: &Self::Input) -> i32 {
+        let mut map = OctopusMap::new(energy.clone());
+        while map.step_cnt < 100 {
+            map.step();
+            map.clean_step();
+        }
+        map.total_flash_cnt
+    }
+
+    fn part2(energy: &Self::Input) -> u64 {
+        synchronise(energy, &mut Recorder::off())
+            .expect("parse checks that the octopuses synchronise")
+    }
+}
+
+impl Visualise for Solver {
+    /// The octopuses flashing until they all flash together.
+    fn visualise(energy: &Self::Input, recorder: &mut Recorder) {
+        synchronise(energy, recorder);
+    }
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` grid (at least 2) of octopuses that all flash
+    /// together within a thousand steps. Random grids rarely do, so fewer
+    /// and fewer octopuses are set apart from the rest until one does.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        let mut apart = 1.0;
+        loop {
+            let level = rng.range(0..=9);
+            let energy = Grid::from_fn(side, side, |_| {
+                if rng.chance(apart) {
+                    rng.range(0..=9)
+                } else {
+                    level
+                }
+            });
+            let mut map = OctopusMap::new(energy.map(|&e| Octopus::new(e as i32)));
+            let synchronises = (0..1000).any(|_| {
+                map.step();
+                let all = map.is_all_flash();
+                map.clean_step();
+                all
+            });
+            if synchronises {
+                return energy.to_string();
+            }
+            apart *= 0.8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flashes_after() {
+        let energy = Solver::parse(include_str!("sample")).unwrap();
+        assert_eq!(flashes_after(&energy, 10), 204);
+        assert_eq!(flashes_after(&energy, 100), 1656);
+        // all of them flash together on step 195 and every tenth after
+        let synchronised = flashes_after(&energy, 195);
+        assert_eq!(flashes_after(&energy, 204), synchronised);
+        assert_eq!(flashes_after(&energy, 205), synchronised + 100);
+        assert_eq!(
+            flashes_after(&energy, 1_000_000_000_000),
+            synchronised + 100 * 99_999_999_980
+        );
+    }
+
+    #[test]
+    fn test_never_synchronised() {
+        // two octopuses five steps apart that never catch up with each other
+        let e = Solver::parse("05").unwrap_err();
+        assert_eq!(
+            e.message,
+            "expected octopuses that all flash together at some point"
+        );
+        assert_eq!(Solver::part2(&Solver::parse("01").unwrap()), 9);
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{invalid_char, split_once, ParseError},
+    solution::Solution,
+};
+use tracing::{debug, trace};
+
+#[derive(Clone)]
+pub struct Graph {
+    graph: Vec<Vec<i32>>,
+    visited: HashSet<usize>,
+    small_cave_visited: HashMap<usize, usize>,
+    path_cnt1: usize,
+    path_cnt2: usize,
+    cave_index: HashMap<String, usize>,
+}
+
+impl Graph {
+    fn new(cave_index: HashMap<String, usize>) -> Self {
+        Self {
+            graph: vec![],
+            visited: HashSet::new(),
+            small_cave_visited: HashMap::new(),
+            path_cnt1: 0,
+            path_cnt2: 0,
+            cave_index,
+        }
+    }
+    fn build(&mut self, paths: &[Path]) {
+        self.graph.reserve(self.cave_index.len());
+        for _ in 0..self.cave_index.len() {
+            self.graph.push([0].repeat(self.cave_index.len()));
+        }
+        for path in paths {
+            let i = self.cave_index.get(path.start).unwrap();
+            let j = self.cave_index.get(path.end).unwrap();
+            *self.graph.get_mut(*i).unwrap().get_mut(*j).unwrap() = 1;
+            *self.graph.get_mut(*j).unwrap().get_mut(*i).unwrap() = 1;
+        }
+    }
+    /// The adjacency matrix, one row per cave.
+    fn adjacency(&self) -> String {
+        self.graph
+            .iter()
+            .map(|row| row.iter().map(|col| format!(" {col} ")).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    fn get_pos(&self, pos: (usize, usize)) -> Option<&i32> {
+        if let Some(v) = self.graph.get(pos.0) {
+            return v.get(pos.1);
+        }
+        None
+    }
+    fn is_small(&self, idx: usize) -> bool {
+        for (k, v) in &self.cave_index {
+            if *v == idx && k.chars().next().unwrap().is_ascii_lowercase() {
+                return true;
+            }
+        }
+        false
+    }
+    fn is_small_cave_visited_twice(&self) -> bool {
+        for v in self.small_cave_visited.values() {
+            if *v >= 2 {
+                return true;
+            }
+        }
+        false
+    }
+    fn dfs(&mut self, start: usize, end: usize) {
+        if start == end {
+            self.path_cnt1 += 1;
+            return;
+        }
+        for i in 0..self.graph.len() {
+            if self.get_pos((start, i)) == Some(&1) && !self.visited.contains(&i) {
+                if self.is_small(i) {
+                    self.visited.insert(i);
+                }
+                self.dfs(i, end);
+                self.visited.remove(&i);
+            }
+        }
+    }
+
+    fn dfs2(&mut self, start: usize, end: usize) {
+        if start == end {
+            self.path_cnt2 += 1;
+            return;
+        }
+        for i in 0..self.graph.len() {
+            if self.get_pos((start, i)) == Some(&1) && !self.visited.contains(&i) {
+                if self.is_small(i) {
+                    let twice = self.is_small_cave_visited_twice();
+                    if let Some(n) = self.small_cave_visited.get_mut(&i) {
+                        if twice {
+                            continue;
+                        }
+                        *n += 1;
+                        if *n >= 2 {
+                            self.visited.insert(i);
+                        }
+                    } else {
+                        self.small_cave_visited.insert(i, 1);
+                        if twice {
+                            self.visited.insert(i);
+                        }
+                    }
+                }
+                self.dfs2(i, end);
+                self.visited.remove(&i);
+                if let Some(n) = self.small_cave_visited.get_mut(&i) {
+                    *n -= 1;
+                    if *n == 0 {
+                        self.small_cave_visited.remove(&i);
+                    }
+                }
+            }
+        }
+    }
+    fn get_cave_index(&self, k: &str) -> Option<usize> {
+        if let Some(v) = self.cave_index.get(k) {
+            return Some(*v);
+        }
+        None
+    }
+    fn part1(&mut self) -> usize {
+        let start = self.get_cave_index("start").unwrap();
+        let end = self.get_cave_index("end").unwrap();
+        self.visited.clear();
+        self.visited.insert(start);
+        self.dfs(start, end);
+        self.path_cnt1
+    }
+    fn part2(&mut self) -> usize {
+        let start = self.get_cave_index("start").unwrap();
+        let end = self.get_cave_index("end").unwrap();
+        self.visited.clear();
+        self.visited.insert(start);
+        self.dfs2(start, end);
+        self.path_cnt2
+    }
+}
+
+struct Path<'a> {
+    start: &'a str,
+    end: &'a str,
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut paths = vec![];
+        let mut cave_index = HashMap::<String, usize>::new();
+        for line in s.trim().lines() {
+            let (start, end) = split_once(s, line.trim(), "-")?;
+            for cave in [start, end] {
+                if let Some(c) = invalid_char(cave, |c| c.is_ascii_alphabetic()) {
+                    return Err(ParseError::at(s, c, "expected a cave name"));
+                }
+                if cave.is_empty() {
+                    return Err(ParseError::at(s, cave, "expected a cave name"));
+                }
+            }
+            paths.push(Path { start, end });
+            let idx = cave_index.len();
+            if !cave_index.contains_key(start) {
+                cave_index.insert(start.to_string(), idx);
+            }
+            let idx = cave_index.len();
+            if !cave_index.contains_key(end) {
+                cave_index.insert(end.to_string(), idx);
+            }
+        }
+        for cave in ["start", "end"] {
+            if !cave_index.contains_key(cave) {
+                return Err(ParseError::end(s, format!("expected a path from {cave}")));
+            }
+        }
+        let mut graph = Graph::new(cave_index);
+        graph.build(&paths);
+        debug!(
+            caves = graph.cave_index.len(),
+            paths = paths.len(),
+            "built the cave graph"
+        );
+        trace!("adjacency:\n{}", graph.adjacency());
+        Ok(graph)
+    }
+
+    fn part1(graph: &Self::Input) -> usize {
+        graph.clone().part1()
+    }
+
+    fn part2(graph: &Self::Input) -> usize {
+        graph.clone().part2()
+    }
+}
+
+impl Generate for Solver {
+    /// `size` caves besides start and end, connected, with no two big caves
+    /// next to each other so that the paths are finite.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = vec!["start".to_string(), "end".to_string()];
+        let mut big = vec![false, false];
+        let mut paths = vec![];
+        for i in 0..size {
+            let is_big = rng.chance(0.3);
+            let name = format!(
+                "{}{}",
+                (b'a' + (i / 26) as u8 % 26) as char,
+                (b'a' + (i % 26) as u8) as char
+            );
+            names.push(if is_big { name.to_uppercase() } else { name });
+            big.push(is_big);
+        }
+        let mut edges = std::collections::HashSet::new();
+        let mut link = |a: usize, b: usize, paths: &mut Vec<String>| {
+            if a != b && !(big[a] && big[b]) && edges.insert((a.min(b), a.max(b))) {
+                paths.push(format!("{}-{}", names[a], names[b]));
+            }
+        };
+        for cave in 1..names.len() {
+            // a small cave to hang off, so the caves stay connected
+            let small = (0..cave).filter(|&c| !big[c]).collect::<Vec<_>>();
+            link(cave, *rng.pick(&small), &mut paths);
+        }
+        for _ in 0..size / 2 {
+            link(rng.below(names.len()), rng.below(names.len()), &mut paths);
+        }
+        rng.shuffle(&mut paths);
+        paths.join("\n")
+    }
+}
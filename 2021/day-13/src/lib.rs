@@ -0,0 +1,243 @@
+use std::vec;
+
+use aoc_common::{
+    compress::Compressed,
+    generate::{Generate, Rng},
+    ocr,
+    parse::{number, split_once, strip_prefix, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Paper {
+    /// Width and height.
+    size: [i64; 2],
+    space: Compressed<2>,
+    /// Whether each cell of `space` holds a dot.
+    dots: Vec<bool>,
+}
+
+impl Paper {
+    fn new(size: [i64; 2], dots: &[[i64; 2]]) -> Self {
+        let space = Compressed::of_points(dots.iter().copied());
+        let mut cells = vec![false; space.len()];
+        for dot in dots {
+            cells[space.index(space.cell(*dot).unwrap())] = true;
+        }
+        Self {
+            size,
+            space,
+            dots: cells,
+        }
+    }
+
+    fn dots(&self) -> impl Iterator<Item = [i64; 2]> + '_ {
+        self.space
+            .cells()
+            .filter(|cell| self.dots[self.space.index(*cell)])
+            .map(|cell| self.space.cuboid(cell).axes.map(|axis| axis.start))
+    }
+
+    fn is_dot(&self, point: [i64; 2]) -> bool {
+        self.space
+            .cell(point)
+            .is_some_and(|cell| self.dots[self.space.index(cell)])
+    }
+
+    fn fold(&mut self, instruct: &FoldInstruct) {
+        let axis = match instruct.axis {
+            Axis::X => 0,
+            Axis::Y => 1,
+        };
+        let line = instruct.value as i64;
+        let folded = self
+            .dots()
+            .filter(|dot| dot[axis] != line)
+            .map(|mut dot| {
+                if dot[axis] > line {
+                    dot[axis] = 2 * line - dot[axis];
+                }
+                dot
+            })
+            .collect::<Vec<_>>();
+        let mut size = self.size;
+        size[axis] = line;
+        *self = Self::new(size, &folded);
+    }
+
+    fn visible(&self) -> i32 {
+        self.dots.iter().filter(|dot| **dot).count() as i32
+    }
+
+    fn part1(&mut self, ins: &FoldInstruct) -> i32 {
+        self.fold(ins);
+        self.visible()
+    }
+
+    fn part2(&mut self, inss: &[FoldInstruct]) -> String {
+        for ins in inss {
+            self.fold(ins);
+        }
+        ocr::read_art_or_keep(self.to_string())
+    }
+}
+
+impl std::fmt::Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.size[1] {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.size[0] {
+                write!(f, "{}", if self.is_dot([x, y]) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        match s {
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            _ => Err(ParseError::at(input, s, "expected fold axis x or y")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FoldInstruct {
+    axis: Axis,
+    value: usize,
+}
+
+impl FoldInstruct {
+    fn new(axis: Axis, value: usize) -> Self {
+        Self { axis, value }
+    }
+}
+
+fn parse_input(s: &str) -> Result<(Paper, Vec<FoldInstruct>), ParseError> {
+    let mut dots_end = false;
+
+    let mut dots: Vec<[i64; 2]> = vec![];
+    let mut fold_instructs: Vec<FoldInstruct> = vec![];
+    let mut max_x = 0;
+    let mut max_y = 0;
+
+    for line in s.trim().lines() {
+        if line.is_empty() {
+            dots_end = true;
+            continue;
+        }
+        if !dots_end {
+            let (x, y) = split_once(s, line, ",")?;
+            let x = number::<u16>(s, x)?.into();
+            let y = number::<u16>(s, y)?.into();
+            dots.push([x, y]);
+            if x > max_x {
+                max_x = x;
+            }
+            if y > max_y {
+                max_y = y;
+            }
+        } else {
+            let fold_rule = strip_prefix(s, line, "fold along ")?;
+            let (fold_axis, fold_point) = split_once(s, fold_rule, "=")?;
+            fold_instructs.push(FoldInstruct::new(
+                Axis::parse(s, fold_axis)?,
+                number(s, fold_point)?,
+            ));
+        }
+    }
+    if fold_instructs.is_empty() {
+        return Err(ParseError::end(s, "expected fold instructions"));
+    }
+
+    Ok((Paper::new([max_x + 1, max_y + 1], &dots), fold_instructs))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = (Paper, Vec<FoldInstruct>);
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1((paper, fold_instructs): &Self::Input) -> i32 {
+        paper.clone().part1(&fold_instructs[0])
+    }
+
+    fn part2((paper, fold_instructs): &Self::Input) -> String {
+        paper.clone().part2(fold_instructs)
+    }
+}
+
+impl Generate for Solver {
+    /// Dots folding into a message of `size` letters (1 to 400), unfolded
+    /// five times along x and seven along y like the puzzle's.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const LETTERS: &[u8] = b"ABCEFGHIJKLOPRSUYZ";
+        // letters lighting their top left pixel, where the bottom right dot
+        // folds to: it sets the size of the paper
+        const FIRST: &[u8] = b"BEFHKLPRUYZ";
+        let mut text = String::from(*rng.pick(FIRST) as char);
+        for _ in 1..size.clamp(1, 400) {
+            text.push(*rng.pick(LETTERS) as char);
+        }
+        let art = ocr::render(&text).unwrap();
+        let mut dots = art
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.bytes()
+                    .enumerate()
+                    .filter(|(_, b)| *b == b'#')
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect::<Vec<_>>();
+        let mut extent = [5 * text.len(), 6];
+        let mut axes = [[0; 5].as_slice(), &[1; 7]].concat();
+        rng.shuffle(&mut axes);
+        let mut folds = vec![];
+        for axis in axes {
+            let line = extent[axis];
+            extent[axis] = 2 * line + 1;
+            let mirror = |mut dot: (usize, usize)| {
+                let v = if axis == 0 { &mut dot.0 } else { &mut dot.1 };
+                *v = 2 * line - *v;
+                dot
+            };
+            dots = dots
+                .into_iter()
+                .flat_map(|dot| match rng.below(3) {
+                    0 => vec![dot],
+                    1 => vec![mirror(dot)],
+                    _ => vec![dot, mirror(dot)],
+                })
+                .collect();
+            folds.push(format!("fold along {}={line}", ["x", "y"][axis]));
+        }
+        folds.reverse();
+        dots.push((extent[0] - 1, extent[1] - 1));
+        dots.sort_unstable();
+        dots.dedup();
+        rng.shuffle(&mut dots);
+        let dots = dots.iter().map(|(x, y)| format!("{x},{y}"));
+        dots.chain([String::new()])
+            .chain(folds)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,296 @@
+use std::{collections::HashMap, ops::Sub};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    matrix::{Element, Matrix},
+    parse::{invalid_char, split_once, ParseError},
+    solution::Solution,
+};
+
+#[cfg(test)]
+mod reference;
+
+fn polymerize(polymer_template: &str, rules: &HashMap<String, char>, steps: usize) -> usize {
+    // build a 26*26 table to store adjoint relation in polymer template
+    let mut adjoint_table: Vec<Vec<usize>> = vec![vec![0usize; 26]; 26];
+
+    let first_char = polymer_template.chars().next().unwrap();
+    let last_char = polymer_template.chars().last().unwrap();
+
+    (0..polymer_template.len() - 1).for_each(|i| {
+        if let Some(s) = polymer_template.get(i..=i + 1) {
+            let x = *s.chars().collect::<Vec<_>>().first().unwrap() as usize - 'A' as usize;
+            let y = *s.chars().collect::<Vec<_>>().last().unwrap() as usize - 'A' as usize;
+            adjoint_table[y][x] += 1;
+        }
+    });
+
+    for _ in 0..steps {
+        // every step we record new adjoint point in map
+        let mut map: HashMap<(usize, usize), usize> = HashMap::new();
+        adjoint_table
+            .iter_mut()
+            .enumerate()
+            .for_each(|(row, cols)| {
+                for (col, cnt) in cols.iter_mut().enumerate() {
+                    if *cnt > 0 {
+                        let k = get_rule(col, row);
+                        if let Some(c) = rules.get(&k) {
+                            let v = map.entry((col, get_index(*c))).or_insert(0);
+                            *v += *cnt;
+                            let v = map.entry((get_index(*c), row)).or_insert(0);
+                            *v += *cnt;
+                            *cnt = 0;
+                        }
+                    }
+                }
+            });
+        for ((x, y), v) in map {
+            // merge back new adjoin relation to table
+            adjoint_table[y][x] += v;
+        }
+    }
+    subtraction(&adjoint_table, first_char, last_char)
+}
+
+/// How many of each element there are after `steps` steps, however many,
+/// counted in `T`. The pair counts go through one matrix per step, over
+/// every pair of the elements in the template or the rules. An empty
+/// template stays empty.
+pub fn elements_after<T: Element>(
+    template: &str,
+    rules: &HashMap<String, char>,
+    steps: u64,
+) -> Vec<(char, T)> {
+    let Some(last) = template.chars().last() else {
+        return vec![];
+    };
+    let mut elements = template
+        .chars()
+        .chain(rules.iter().flat_map(|(pair, c)| pair.chars().chain([*c])))
+        .collect::<Vec<_>>();
+    elements.sort_unstable();
+    elements.dedup();
+    let k = elements.len();
+    let pair = |a: char, b: char| {
+        elements.binary_search(&a).unwrap() * k + elements.binary_search(&b).unwrap()
+    };
+
+    // a pair turns into the two around the element inserted, or stays
+    let mut entries = vec![];
+    for &a in &elements {
+        for &b in &elements {
+            match rules.get(&format!("{a}{b}")) {
+                Some(&c) => {
+                    entries.push((pair(a, c), pair(a, b), 1));
+                    entries.push((pair(c, b), pair(a, b), 1));
+                }
+                None => entries.push((pair(a, b), pair(a, b), 1)),
+            }
+        }
+    }
+    let mut start = vec![T::zero(); k * k];
+    for (a, b) in template.chars().zip(template.chars().skip(1)) {
+        let n = &mut start[pair(a, b)];
+        *n = n.add(&T::one());
+    }
+    let pairs = Matrix::from_entries(k * k, entries)
+        .pow(steps)
+        .apply(&start);
+
+    // every element but the last one starts a pair
+    elements
+        .iter()
+        .enumerate()
+        .map(|(i, &e)| {
+            let starting = pairs[i * k..(i + 1) * k]
+                .iter()
+                .fold(T::zero(), |sum, n| sum.add(n));
+            let count = if e == last {
+                starting.add(&T::one())
+            } else {
+                starting
+            };
+            (e, count)
+        })
+        .collect()
+}
+
+/// The most common element's count minus the least common's after `steps`
+/// steps, however many, zero when there are none.
+pub fn spread_after<T: Element + Ord + Sub<Output = T>>(
+    template: &str,
+    rules: &HashMap<String, char>,
+    steps: u64,
+) -> T {
+    let mut counts = elements_after::<T>(template, rules, steps)
+        .into_iter()
+        .map(|(_, n)| n)
+        .filter(|n| !n.is_zero())
+        .collect::<Vec<_>>();
+    counts.sort_unstable();
+    match (counts.first(), counts.last()) {
+        (Some(least), Some(most)) => most.clone() - least.clone(),
+        _ => T::zero(),
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The polymer template and the pair insertion rules.
+    type Input = (String, HashMap<String, char>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let elements = |e: &str, len: Option<usize>| -> Result<(), ParseError> {
+            if let Some(c) = invalid_char(e, |c| c.is_ascii_uppercase()) {
+                return Err(ParseError::at(s, c, "expected an element A to Z"));
+            }
+            match len {
+                Some(len) if e.len() != len => {
+                    Err(ParseError::at(s, e, format!("expected {len} elements")))
+                }
+                _ if e.is_empty() => Err(ParseError::at(s, e, "expected elements")),
+                _ => Ok(()),
+            }
+        };
+        let mut lines = s.lines();
+        let polymer_template = lines
+            .next()
+            .ok_or_else(|| ParseError::end(s, "expected a polymer template"))?;
+        elements(polymer_template, None)?;
+        let mut rules: HashMap<String, char> = HashMap::new();
+        for line in lines.filter(|line| !line.is_empty()) {
+            let (pair, c) = split_once(s, line, " -> ")?;
+            elements(pair, Some(2))?;
+            elements(c, Some(1))?;
+            rules.insert(pair.into(), c.chars().next().unwrap());
+        }
+        Ok((polymer_template.to_string(), rules))
+    }
+
+    fn part1((polymer_template, rules): &Self::Input) -> usize {
+        polymerize(polymer_template, rules, 10)
+    }
+
+    fn part2((polymer_template, rules): &Self::Input) -> usize {
+        polymerize(polymer_template, rules, 40)
+    }
+}
+
+// every single char except the 1st and last in polymer template has been calculated twice
+// so we need add 1 for the 1st and last char, then divide them all by 2
+fn subtraction(board: &[Vec<usize>], first_char: char, last_char: char) -> usize {
+    let mut sum = vec![0usize; 26];
+    sum[get_index(first_char)] += 1;
+    sum[get_index(last_char)] += 1;
+
+    board.iter().enumerate().for_each(|(y, row)| {
+        row.iter().enumerate().for_each(|(x, col)| {
+            sum[x] += *col;
+            sum[y] += *col;
+        });
+    });
+
+    sum.retain(|x| *x > 0);
+    sum.sort_unstable();
+    (sum.last().unwrap() - sum.first().unwrap()) / 2
+}
+
+fn get_index(c: char) -> usize {
+    c as usize - 'A' as usize
+}
+
+fn get_rule(x: usize, y: usize) -> String {
+    let mut s = String::new();
+    s.push((x as u8 + b'A') as char);
+    s.push((y as u8 + b'A') as char);
+    s
+}
+
+impl Generate for Solver {
+    /// A template of `size` elements (at least 2) out of up to ten, with a
+    /// rule for every pair of them.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters = ('A'..='Z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        letters.truncate(rng.range(2..=10) as usize);
+        let template = (0..size.max(2))
+            .map(|_| *rng.pick(&letters))
+            .collect::<String>();
+        let mut rules = vec![];
+        for a in &letters {
+            for b in &letters {
+                rules.push(format!("{a}{b} -> {}", rng.pick(&letters)));
+            }
+        }
+        rng.shuffle(&mut rules);
+        format!("{template}\n\n{}", rules.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::matrix::{BigUint, Mod};
+
+    #[test]
+    fn test_polymerize_reference() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let size = rng.below(8) + 2;
+            let input = Solver::generate(&mut rng, size);
+            let (template, mut rules) = Solver::parse(&input).unwrap();
+            // pairs without a rule are left as they are
+            rules.retain(|_, _| !rng.chance(0.2));
+            for steps in 0..=8 {
+                assert_eq!(
+                    polymerize(&template, &rules, steps),
+                    reference::polymerize(&template, &rules, steps),
+                    "{template} {rules:?} after {steps} steps"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_template() {
+        let rules = HashMap::from([("AB".to_string(), 'C')]);
+        assert!(elements_after::<u64>("", &rules, 5).is_empty());
+        assert_eq!(spread_after::<u64>("", &rules, 5), 0);
+    }
+
+    #[test]
+    fn test_spread_after() {
+        let (template, rules) = Solver::parse(include_str!("sample")).unwrap();
+        assert_eq!(spread_after::<u128>(&template, &rules, 10), 1588);
+        assert_eq!(spread_after::<u128>(&template, &rules, 40), 2188189693529);
+        for steps in [0, 1, 5, 25] {
+            let spread = polymerize(&template, &rules, steps) as u128;
+            assert_eq!(
+                spread_after::<u128>(&template, &rules, steps as u64),
+                spread
+            );
+        }
+        // far past what fits a u128, the big and the modular counts agree
+        let big = elements_after::<BigUint>(&template, &rules, 1000);
+        let modular = elements_after::<Mod<1_000_000_007>>(&template, &rules, 1000);
+        for ((b, big), (m, modular)) in big.into_iter().zip(modular) {
+            assert_eq!((b, big % 1_000_000_007u32), (m, BigUint::from(modular.0)));
+        }
+        // worked out on its own with a plain modular matrix power
+        let modular = elements_after::<Mod<1_000_000_007>>(&template, &rules, 1_000_000_000);
+        let counts = modular.iter().map(|(e, n)| (*e, n.0)).collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            [
+                ('B', 372159003),
+                ('C', 454545390),
+                ('H', 636363682),
+                ('N', 958806943)
+            ]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Pair insertion done on the polymer itself, to check the pair counts
+//! against.
+
+use std::collections::HashMap;
+
+/// Most common minus least common element after `steps` insertions.
+pub fn polymerize(template: &str, rules: &HashMap<String, char>, steps: usize) -> usize {
+    let mut polymer = template.chars().collect::<Vec<_>>();
+    for _ in 0..steps {
+        let mut next = vec![polymer[0]];
+        for pair in polymer.windows(2) {
+            let key = pair.iter().collect::<String>();
+            if let Some(&c) = rules.get(&key) {
+                next.push(c);
+            }
+            next.push(pair[1]);
+        }
+        polymer = next;
+    }
+    let mut counts = HashMap::new();
+    for c in polymer {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
@@ -0,0 +1,94 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    grid::{Grid, Pos},
+    parse::ParseError,
+    search::{astar, Path},
+    solution::Solution,
+};
+
+/// Safest way from the top left to the bottom right corner, the cost of a
+/// step is the risk level of the cell entered.
+fn lowest_risk(risk: &Grid<i32>) -> Option<Path<Pos, i32>> {
+    let goal = (risk.height() - 1, risk.width() - 1);
+    // every cell costs at least 1, so the manhattan distance never overestimates
+    let remaining = |p: &Pos| (goal.0 - p.0 + goal.1 - p.1) as i32;
+    astar(
+        [(0, 0)],
+        |p| risk.neighbors4(*p).map(|n| (n, risk[n])),
+        remaining,
+        |p| *p == goal,
+    )
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        // a risk of at least 1 everywhere keeps the A* heuristic admissible
+        let map = Grid::parse(s, |c| c.to_digit(10).filter(|&d| d > 0).map(|n| n as i32))?;
+        if map.cells().is_empty() {
+            return Err(ParseError::end(s, "expected a map of risk levels"));
+        }
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        lowest_risk(input).unwrap().cost
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input).unwrap()
+    }
+}
+
+fn part2(input: &Grid<i32>) -> Option<i32> {
+    // the full map is the input tiled 5x5, every tile away from the top left
+    // one adds 1 to the risk level, wrapping from 9 back to 1
+    let (height, width) = (input.height(), input.width());
+    let input_extend = Grid::from_fn(height * 5, width * 5, |(y, x)| {
+        let v = input[(y % height, x % width)] + (y / height + x / width) as i32;
+        (v - 1) % 9 + 1
+    });
+    lowest_risk(&input_extend).map(|path| path.cost)
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` map of risk levels.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| rng.range(1..=9).to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let input = Grid::parse_digits(SAMPLE).unwrap().map(|n| *n as i32);
+        let path = lowest_risk(&input).unwrap();
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(9, 9)));
+        // the start is not entered, so its risk is not counted
+        let risk = path.nodes[1..].iter().map(|p| input[*p]).sum::<i32>();
+        assert_eq!(risk, path.cost);
+    }
+    #[test]
+    fn test_part2_sample() {
+        let input = Grid::parse_digits(SAMPLE).unwrap().map(|n| *n as i32);
+        assert_eq!(part2(&input), Some(315));
+    }
+}
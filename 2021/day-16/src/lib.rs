@@ -0,0 +1,321 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{invalid_char, ParseError},
+    solution::Solution,
+};
+use nom::{bytes::complete::take, error::ErrorKind};
+
+#[derive(Debug)]
+enum PacketTypeID {
+    Literal,
+    Operator(OpType),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum OpType {
+    Literal,
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl From<usize> for OpType {
+    fn from(i: usize) -> Self {
+        match i {
+            0 => Self::Sum,
+            1 => Self::Product,
+            2 => Self::Min,
+            3 => Self::Max,
+            5 => Self::Gt,
+            6 => Self::Lt,
+            7 => Self::Eq,
+            _ => {
+                unreachable!()
+            }
+        }
+    }
+}
+
+impl From<usize> for PacketTypeID {
+    fn from(i: usize) -> Self {
+        match i {
+            4 => Self::Literal,
+            n => Self::Operator(n.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Expr {
+    version: usize,
+    op: OpType,
+    value: usize,
+    args: Option<Vec<Expr>>,
+}
+
+impl Expr {
+    fn new(op: OpType, value: usize, args: Option<Vec<Expr>>) -> Self {
+        Self {
+            version: 0,
+            op,
+            value,
+            args,
+        }
+    }
+
+    fn version_sum(&self) -> usize {
+        self.version
+            + self
+                .args
+                .iter()
+                .flatten()
+                .map(Expr::version_sum)
+                .sum::<usize>()
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Expr;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        if let Some(c) = invalid_char(s, |c| c.is_ascii_hexdigit()) {
+            return Err(ParseError::at(s, c, "expected a hex digit"));
+        }
+        let mut binary = "".to_owned();
+        s.chars().for_each(|c| {
+            let d = c.to_digit(16).unwrap();
+            let b = format!("{:0>4b}", d);
+            binary.push_str(&b);
+        });
+
+        let (_, root_expr) = parse_packet(binary.as_str()).map_err(|e| {
+            // report the hex digit holding the first bit that went wrong
+            let i = ((binary.len() - e.at.len()) / 4).min(s.len());
+            ParseError::at(s, &s[i..(i + 1).min(s.len())], e.message)
+        })?;
+        root_expr.ok_or_else(|| ParseError::end(s, "expected a packet"))
+    }
+
+    fn part1(root_expr: &Self::Input) -> usize {
+        root_expr.version_sum()
+    }
+
+    fn part2(root_expr: &Self::Input) -> usize {
+        eval_expr(root_expr)
+    }
+}
+
+/// Where a packet stopped making sense in the bit string, and why.
+struct BitsError<'a> {
+    at: &'a str,
+    message: &'static str,
+}
+
+type PacketResult<'a> = Result<(&'a str, Option<Expr>), BitsError<'a>>;
+
+fn parse_packet(input: &str) -> PacketResult<'_> {
+    if input.is_empty() {
+        return Ok(("", None));
+    }
+    let (input, packet_version) = take_bits(input, 3)?;
+    let (input, packet_type_id) = take_bits(input, 3)?;
+    let packet_version = value(packet_version)?;
+
+    let (input, expr) = match value(packet_type_id)?.into() {
+        PacketTypeID::Literal => parse_literal(input),
+        PacketTypeID::Operator(o) => parse_operator(input, o),
+    }?;
+    Ok((
+        input,
+        expr.map(|expr| Expr {
+            version: packet_version,
+            ..expr
+        }),
+    ))
+}
+
+fn parse_literal(input: &str) -> PacketResult<'_> {
+    if input.is_empty() {
+        return Ok(("", None));
+    }
+    let start = input;
+    let (mut input, mut len_type_id) = take_bits(input, 5)?;
+    let mut value = "".to_string();
+    loop {
+        value.push_str(&len_type_id[1..]);
+        if len_type_id.starts_with('0') {
+            break;
+        }
+        (input, len_type_id) = take_bits(input, 5)?;
+    }
+    let v = usize::from_str_radix(&value, 2).map_err(|_| BitsError {
+        at: start,
+        message: "literal does not fit 64 bits",
+    })?;
+    Ok((input, Some(Expr::new(OpType::Literal, v, None))))
+}
+
+fn parse_operator(input: &str, op: OpType) -> PacketResult<'_> {
+    let start = input;
+    let (input, len_type_id) = take_bits(input, 1)?;
+    let len_type_id = value(len_type_id)?;
+    let len_of_sub_packet_bits: usize = if len_type_id == 0 { 15 } else { 11 };
+
+    let (mut input, len_of_sub_packet) = take_bits(input, len_of_sub_packet_bits)?;
+
+    let len_of_sub_packet = value(len_of_sub_packet)?;
+    let mut expr = Expr::new(op, 0, None);
+    let mut args = vec![];
+    if len_type_id == 0 {
+        let (rest, mut sub_packet) = take_bits(input, len_of_sub_packet)?;
+        while !sub_packet.is_empty() {
+            let (s, sub_expr) = parse_packet(sub_packet)?;
+            if let Some(sub) = sub_expr {
+                args.push(sub);
+            }
+            sub_packet = s;
+        }
+        input = rest;
+    } else {
+        for _ in 0..len_of_sub_packet {
+            let (s, expr) = parse_packet(input)?;
+            if let Some(sub) = expr {
+                args.push(sub);
+            }
+            input = s;
+        }
+    }
+    let arity_ok = match expr.op {
+        OpType::Gt | OpType::Lt | OpType::Eq => args.len() == 2,
+        _ => !args.is_empty(),
+    };
+    if !arity_ok {
+        return Err(BitsError {
+            at: start,
+            message: "wrong number of sub-packets for the operator",
+        });
+    }
+    expr.args = Some(args);
+    Ok((input, Some(expr)))
+}
+
+fn eval_expr(root: &Expr) -> usize {
+    let mut arg_value = vec![];
+    if let Some(args) = &root.args {
+        for arg in args {
+            arg_value.push(eval_expr(arg));
+        }
+    }
+    match root.op {
+        OpType::Sum => arg_value.iter().sum(),
+        OpType::Product => arg_value.iter().product(),
+        OpType::Min => *arg_value.iter().min().unwrap(),
+        OpType::Max => *arg_value.iter().max().unwrap(),
+        OpType::Gt => (arg_value[0] > arg_value[1]) as usize,
+        OpType::Lt => (arg_value[0] < arg_value[1]) as usize,
+        OpType::Eq => (arg_value[0] == arg_value[1]) as usize,
+        OpType::Literal => root.value,
+    }
+}
+
+/// Split the first `n` bits off `input`.
+fn take_bits(input: &str, n: usize) -> Result<(&str, &str), BitsError<'_>> {
+    take::<_, _, (&str, ErrorKind)>(n)(input).map_err(|_| BitsError {
+        at: input,
+        message: "packet ends early",
+    })
+}
+
+fn value(bits: &str) -> Result<usize, BitsError<'_>> {
+    usize::from_str_radix(bits, 2).map_err(|_| BitsError {
+        at: bits,
+        message: "value does not fit 64 bits",
+    })
+}
+
+/// The bits of a random packet, made of at most `budget` packets nested no
+/// deeper than `depth`. Only literals up to 15 and comparisons go into
+/// products so that the value fits.
+fn packet(rng: &mut Rng, budget: &mut usize, depth: usize, in_product: bool) -> String {
+    *budget = budget.saturating_sub(1);
+    let version = format!("{:03b}", rng.below(8));
+    let types: &[usize] = if in_product {
+        &[4, 5, 6, 7]
+    } else {
+        &[0, 1, 2, 3, 4, 5, 6, 7]
+    };
+    let type_id = if *budget < 2 || depth == 0 {
+        4
+    } else {
+        *rng.pick(types)
+    };
+    if type_id == 4 {
+        let value = if in_product {
+            rng.range(0..=15)
+        } else {
+            rng.range(0..=1 << 20)
+        };
+        let mut groups = format!("{value:b}");
+        while !groups.len().is_multiple_of(4) {
+            groups.insert(0, '0');
+        }
+        let count = groups.len() / 4;
+        let bits = (0..count)
+            .map(|i| {
+                let flag = if i + 1 < count { '1' } else { '0' };
+                format!("{flag}{}", &groups[i * 4..i * 4 + 4])
+            })
+            .collect::<String>();
+        return format!("{version}100{bits}");
+    }
+    let count = match type_id {
+        5..=7 => 2,
+        _ => rng.range(1..=(*budget).clamp(1, 4) as i64) as usize,
+    };
+    let sub_packets = (0..count)
+        .map(|_| packet(rng, budget, depth - 1, type_id == 1))
+        .collect::<String>();
+    let length = if rng.chance(0.5) && sub_packets.len() < 1 << 15 {
+        format!("0{:015b}", sub_packets.len())
+    } else {
+        format!("1{count:011b}")
+    };
+    format!("{version}{type_id:03b}{length}{sub_packets}")
+}
+
+impl Generate for Solver {
+    /// A transmission of about `size` packets.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut budget = size.max(1);
+        let mut bits = packet(rng, &mut budget, 12, false);
+        while !bits.len().is_multiple_of(8) {
+            bits.push('0');
+        }
+        bits.as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                let nibble = std::str::from_utf8(nibble).unwrap();
+                format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn bool_usize() {
+        assert_eq!(0, (1 == 2) as usize);
+        assert_eq!(0, (1 > 2) as usize);
+        assert_eq!(1, (3 > 2) as usize);
+        assert_eq!(1, (1 == 1) as usize);
+    }
+}
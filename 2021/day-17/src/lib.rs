@@ -0,0 +1,197 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    interval::{Cuboid, Interval},
+    parse::{number, split_once, strip_prefix, ParseError},
+    solution::Solution,
+};
+use tracing::{debug, trace};
+
+/// The target area, `x1..=x2` by `y1..=y2`.
+pub struct Target {
+    area: Cuboid<2>,
+}
+
+impl Target {
+    /// The bounds of the area as `(x1, x2, y1, y2)`, for the equations of
+    /// motion.
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let [x, y] = self.area.axes;
+        (
+            x.start as f64,
+            x.last() as f64,
+            y.start as f64,
+            y.last() as f64,
+        )
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Target;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        // spaces around `=` are accepted, the sample has them
+        let area = strip_prefix(s, s.trim(), "target area:")?;
+        let (x, y) = split_once(s, area, ",")?;
+        let (x, _) = range(s, x, "x")?;
+        let (y, y_range) = range(s, y, "y")?;
+        // the parts count on a probe fired upwards coming back down through
+        // y = 0 and at least one step further before it reaches the target
+        if y.last() > -2 {
+            return Err(ParseError::at(s, y_range, "expected a target below y=-1"));
+        }
+        let area = Cuboid::new([x, y]);
+
+        Ok(Target { area })
+    }
+
+    fn part1(target: &Self::Input) -> usize {
+        let (_, _, y1, y2) = target.bounds();
+        let mut y_maxs = vec![];
+        (1..=(-y1 - 1.0) as usize).for_each(|y| {
+            if let Some(v) = calc_max_y(y as i32, y1, y2) {
+                y_maxs.push(v.1);
+            }
+        });
+        *y_maxs.iter().max().unwrap()
+    }
+
+    fn part2(target: &Self::Input) -> usize {
+        let (x1, x2, y1, y2) = target.bounds();
+        let mut ys = vec![];
+        (1..=(-y1 - 1.0) as usize).for_each(|y| {
+            if let Some(v) = calc_max_y(y as i32, y1, y2) {
+                ys.push(v.0);
+            }
+        });
+        (0..=-y1 as usize).for_each(|y| {
+            let y = -(y as i32);
+            if let Some(v) = calc_max_y(y, y1, y2) {
+                ys.push(v.0);
+            }
+        });
+
+        let mut xs = vec![];
+        (1..=(x2 as usize)).for_each(|x| {
+            let xx = calc_max_x(x as i32, x1, x2);
+            if let Some(v) = xx {
+                xs.push(v);
+            }
+        });
+
+        debug!(xs = xs.len(), ys = ys.len(), "candidate velocities");
+        let velocity = calc_possible_velocity(&xs, &ys, &target.area);
+        velocity.len()
+    }
+}
+
+/// The `axis=lo..hi` in `part` of `s`, with the `lo..hi` it was read from.
+fn range<'a>(s: &str, part: &'a str, axis: &str) -> Result<(Interval, &'a str), ParseError> {
+    let (name, range) = split_once(s, part, "=")?;
+    let name = name.trim();
+    if name != axis {
+        return Err(ParseError::at(s, name, format!("expected {axis:?}")));
+    }
+    let range = range.trim();
+    let (lo, hi_token) = split_once(s, range, "..")?;
+    let (lo, hi) = (number(s, lo)?, number(s, hi_token)?);
+    if lo > hi {
+        return Err(ParseError::at(
+            s,
+            range,
+            "expected a range from low to high",
+        ));
+    }
+    let interval = Interval::inclusive(lo, hi)
+        .ok_or_else(|| ParseError::at(s, hi_token, "expected a number below i64::MAX"))?;
+    Ok((interval, range))
+}
+
+fn calc_possible_velocity(xs: &[i32], ys: &[i32], area: &Cuboid<2>) -> Vec<(i32, i32)> {
+    let [x_range, y_range] = area.axes;
+    let x2 = x_range.last();
+    let y2 = y_range.start;
+    let mut vec = vec![];
+    for x in xs {
+        for y in ys {
+            let mut start_x = i64::from(*x);
+            let mut start_y = i64::from(*y);
+            let mut distance_x = 0;
+            let mut distance_y = 0;
+            loop {
+                distance_x += start_x;
+                distance_y += start_y;
+                if area.contains([distance_x, distance_y]) && !vec.contains(&(*x, *y)) {
+                    vec.push((*x, *y));
+                }
+                if distance_x > x2 || distance_y < y2 {
+                    break;
+                }
+                if start_x > 0 {
+                    start_x -= 1;
+                }
+                start_y -= 1;
+            }
+        }
+    }
+    vec
+}
+
+fn calc_max_x(x: i32, x1: f64, _x2: f64) -> Option<i32> {
+    let _ = unary_root(1.0, -1.0, 2.0 * x1 - x.pow(2) as f64 - x as f64)?.ceil();
+    // let _ = unary_root(1.0, -1.0, 2.0 * y2 - y.pow(2) as f64 - y as f64)?;
+    Some(x)
+}
+
+fn calc_max_y(y: i32, y1: f64, y2: f64) -> Option<(i32, usize)> {
+    let _ = unary_root(1.0, -1.0, 2.0 * y1 - y.pow(2) as f64 - y as f64)?.ceil();
+    let _ = unary_root(1.0, -1.0, 2.0 * y2 - y.pow(2) as f64 - y as f64)?;
+    if y < 0 {
+        return Some((y, 0));
+    }
+    Some((y, (1..=y as usize).sum()))
+}
+
+fn unary_root(a: f64, b: f64, c: f64) -> Option<f64> {
+    let v = b.powf(2.0) - 4.0 * a * c;
+    trace!(a, b, c, v, "discriminant");
+    if v < 0.0 {
+        return None;
+    }
+    Some((-b + v.sqrt()) / (2.0 * a))
+}
+
+impl Generate for Solver {
+    /// A target area about `size` steps (at least 10) away from the probe.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(10) as i64;
+        let x1 = rng.range(size..=3 * size);
+        let x2 = x1 + rng.range(size / 5..=size);
+        let y1 = -rng.range(size..=3 * size);
+        let y2 = y1 + rng.range(size / 5..=size - 2);
+        format!("target area: x={x1}..{x2}, y={y1}..{y2}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_target_above_probe() {
+        let e = Solver::parse("target area: x=20..30, y=-10..-1")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column), (1, 26));
+        assert_eq!(e.message, "expected a target below y=-1");
+        let e = Solver::parse("target area: x=20..30, y=-5..-10")
+            .err()
+            .unwrap();
+        assert_eq!(e.message, "expected a range from low to high");
+        let target = Solver::parse("target area: x=20..30, y=-10..-2").unwrap();
+        assert_eq!(Solver::part1(&target), 45);
+    }
+}
@@ -0,0 +1,342 @@
+use std::cmp::max;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, strip_prefix, ParseError},
+    solution::Solution,
+};
+use tracing::trace;
+
+#[derive(Debug, Clone)]
+enum Elem {
+    N(i32),
+    Pair(Node),
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    left: Box<Elem>,
+    right: Box<Elem>,
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.left.as_ref(), self.right.as_ref()) {
+            (Elem::N(n1), Elem::N(n2)) => {
+                write!(f, "[{},{}]", n1, n2)?;
+            }
+            (Elem::Pair(p), Elem::N(n)) => {
+                write!(f, "[")?;
+                Node::fmt(p, f)?;
+                write!(f, ",{}]", n)?;
+            }
+            (Elem::N(n), Elem::Pair(p)) => {
+                write!(f, "[{},", n)?;
+                Node::fmt(p, f)?;
+                write!(f, "]")?;
+            }
+            (Elem::Pair(p1), Elem::Pair(p2)) => {
+                write!(f, "[")?;
+                Node::fmt(p1, f)?;
+                write!(f, ",")?;
+                Node::fmt(p2, f)?;
+                write!(f, "]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Node {
+    fn add(p1: Node, p2: Node) -> Node {
+        Self {
+            left: Box::new(Elem::Pair(p1)),
+            right: Box::new(Elem::Pair(p2)),
+        }
+    }
+
+    fn need_explode(p: &mut Node, mut deep: i32) -> Option<(i32, i32)> {
+        deep += 1;
+        if deep >= 5 {
+            match (p.left.as_ref(), p.right.as_ref()) {
+                (Elem::N(n1), Elem::N(n2)) => {
+                    trace!(pair = %p, "explode");
+                    return Some((*n1, *n2));
+                }
+                _ => {
+                    panic!("wrong pair");
+                }
+            }
+        }
+        while let Elem::Pair(ref mut left) = p.left.as_mut() {
+            if let Some((n1, n2)) = Self::need_explode(left, deep) {
+                if n1 != -1 && n2 == -1 {
+                    if deep == 1 {
+                        continue;
+                    }
+                    return Some((n1, -1));
+                }
+
+                if n2 != -1 {
+                    if n1 != -1 && n2 != -1 {
+                        *p.left = Elem::N(0);
+                    }
+                    let mut node = p.right.as_mut();
+                    loop {
+                        match node {
+                            Elem::N(n) => {
+                                *n += n2;
+                                break;
+                            }
+                            Elem::Pair(p) => {
+                                if let Elem::N(n) = p.left.as_mut() {
+                                    *n += n2;
+                                    break;
+                                } else {
+                                    node = p.left.as_mut();
+                                }
+                            }
+                        }
+                    }
+                    if n1 == -1 {
+                        continue;
+                    }
+                    return Some((n1, -1));
+                }
+            } else {
+                break;
+            }
+        }
+        while let Elem::Pair(ref mut right) = p.right.as_mut() {
+            if let Some((n1, n2)) = Self::need_explode(right, deep) {
+                if n1 == -1 && n2 != -1 {
+                    if deep == 1 {
+                        continue;
+                    }
+                    return Some((-1, n2));
+                }
+                if n1 != -1 {
+                    if n1 != -1 && n2 != -1 {
+                        *p.right = Elem::N(0);
+                    }
+                    let mut node = p.left.as_mut();
+                    loop {
+                        match node {
+                            Elem::N(n) => {
+                                *n += n1;
+                                break;
+                            }
+                            Elem::Pair(p) => {
+                                if let Elem::N(n) = p.right.as_mut() {
+                                    *n += n1;
+                                    break;
+                                } else {
+                                    node = p.right.as_mut();
+                                }
+                            }
+                        }
+                    }
+                    if n2 == -1 {
+                        continue;
+                    }
+                    return Some((-1, n2));
+                }
+            } else {
+                break;
+            }
+        }
+        None
+    }
+
+    fn split(&mut self) -> bool {
+        match self.left.as_mut() {
+            Elem::N(n) => {
+                if *n >= 10 {
+                    *self.left = Elem::Pair(Node {
+                        left: Box::new(Elem::N(*n / 2)),
+                        right: Box::new(Elem::N(*n - (*n / 2))),
+                    });
+                    return true;
+                }
+            }
+            Elem::Pair(p) => {
+                if Self::split(p) {
+                    return true;
+                }
+            }
+        }
+        match self.right.as_mut() {
+            Elem::N(n) => {
+                if *n >= 10 {
+                    *self.right = Elem::Pair(Node {
+                        left: Box::new(Elem::N(*n / 2)),
+                        right: Box::new(Elem::N(*n - (*n / 2))),
+                    });
+                    return true;
+                }
+            }
+            Elem::Pair(p) => {
+                if Self::split(p) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn explode(&mut self) {
+        Self::need_explode(self, 0);
+    }
+
+    fn magnitude(&self) -> i32 {
+        match (self.left.as_ref(), self.right.as_ref()) {
+            (Elem::N(n1), Elem::N(n2)) => 3 * n1 + 2 * n2,
+            (Elem::N(n1), Elem::Pair(p)) => 3 * n1 + 2 * Self::magnitude(p),
+            (Elem::Pair(p), Elem::N(n2)) => 3 * Self::magnitude(p) + 2 * n2,
+            (Elem::Pair(p1), Elem::Pair(p2)) => 3 * Self::magnitude(p1) + 2 * Self::magnitude(p2),
+        }
+    }
+}
+
+/// Parse one snailfish number from `line`, a line of `input`.
+fn parse_input(input: &str, line: &str) -> Result<Node, ParseError> {
+    let line = line.trim();
+    let (elem, rest) = parse_elem(input, line)?;
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "expected the end of the line"));
+    }
+    match elem {
+        Elem::Pair(p) => Ok(p),
+        Elem::N(_) => Err(ParseError::at(input, line, "expected a pair")),
+    }
+}
+
+/// A regular number or a pair at the start of `s`, with what follows it.
+fn parse_elem<'a>(input: &str, s: &'a str) -> Result<(Elem, &'a str), ParseError> {
+    if let Some(rest) = s.strip_prefix('[') {
+        let (left, rest) = parse_elem(input, rest)?;
+        let rest = strip_prefix(input, rest, ",")?;
+        let (right, rest) = parse_elem(input, rest)?;
+        let rest = strip_prefix(input, rest, "]")?;
+        let pair = Node {
+            left: Box::new(left),
+            right: Box::new(right),
+        };
+        Ok((Elem::Pair(pair), rest))
+    } else {
+        let digits = s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (n, rest) = s.split_at(digits);
+        if n.is_empty() {
+            return Err(ParseError::at(
+                input,
+                &s[..s.chars().next().map_or(0, char::len_utf8)],
+                "expected a number or a pair",
+            ));
+        }
+        Ok((Elem::N(number(input, n)?), rest))
+    }
+}
+
+fn part1(mut snailfish: Vec<Node>) -> i32 {
+    let mut first = snailfish.remove(0);
+    for p in snailfish {
+        first = Node::add(first, p);
+        first.explode();
+        while first.split() {
+            first.explode();
+        }
+        trace!(sum = %first, "reduced");
+    }
+    first.magnitude()
+}
+
+fn part2(snailfish: Vec<Node>) -> i32 {
+    let mut max_sum = 0;
+    for (i, p1) in snailfish.iter().enumerate() {
+        for (j, p2) in snailfish.iter().enumerate() {
+            if i != j {
+                let sum = part1(vec![p1.clone(), p2.clone()]);
+                max_sum = max(max_sum, sum);
+                let sum = part1(vec![p2.clone(), p1.clone()]);
+                max_sum = max(max_sum, sum);
+            }
+        }
+    }
+    max_sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Node>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        if s.trim().is_empty() {
+            return Err(ParseError::end(s, "expected a snailfish number"));
+        }
+        s.lines().map(|line| parse_input(s, line)).collect()
+    }
+
+    fn part1(snailfish_list: &Self::Input) -> i32 {
+        part1(snailfish_list.clone())
+    }
+
+    fn part2(snailfish_list: &Self::Input) -> i32 {
+        part2(snailfish_list.clone())
+    }
+}
+
+/// A snailfish pair inside `depth` others, reduced: no pair ends up inside
+/// four.
+fn snailfish(rng: &mut Rng, depth: usize) -> String {
+    let elem = |rng: &mut Rng| {
+        if depth < 3 && rng.chance(0.6) {
+            snailfish(rng, depth + 1)
+        } else {
+            rng.range(0..=9).to_string()
+        }
+    };
+    let left = elem(rng);
+    let right = elem(rng);
+    format!("[{left},{right}]")
+}
+
+impl Generate for Solver {
+    /// `size` snailfish numbers (at least 2).
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(2))
+            .map(|_| snailfish(rng, 0))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn sample_works() {
+        let s = include_str!("sample");
+        let mut snailfish_list = vec![];
+        for line in s.lines() {
+            let p = parse_input(s, line).unwrap();
+            snailfish_list.push(p);
+        }
+        let snailfish_list_cp = snailfish_list.clone();
+        assert_eq!(4140, part1(snailfish_list));
+        assert_eq!(3993, part2(snailfish_list_cp));
+    }
+    #[test]
+    fn malformed_line() {
+        let s = "[1,2]\n[[1,2]3]";
+        let e = Solver::parse(s).unwrap_err();
+        assert_eq!((e.line, e.column, e.token.as_str()), (2, 7, "3"));
+        assert_eq!(e.message, "expected \",\"");
+        assert_eq!(
+            Solver::parse("").unwrap_err().message,
+            "expected a snailfish number"
+        );
+    }
+}
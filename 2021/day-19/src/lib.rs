@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    point::{Point3, Rotation},
+    solution::Solution,
+};
+use tracing::{debug, trace};
+
+fn parse_beacon(input: &str, line: &str) -> Result<Point3, ParseError> {
+    let xyz = line
+        .split(',')
+        .map(|s| number(input, s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if xyz.len() != 3 {
+        return Err(ParseError::at(input, line, "expected x,y,z coordinates"));
+    }
+    Ok(Point3::new(xyz[0], xyz[1], xyz[2]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    index: usize,
+    position: Point3,
+    beacons: Vec<Point3>,
+}
+
+impl std::cmp::PartialEq for Scanner {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Scanner {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            position: Point3::ORIGIN,
+            beacons: vec![],
+        }
+    }
+
+    fn manhattan_distance(s1: &Scanner, s2: &Scanner) -> usize {
+        s1.position.manhattan(s2.position) as usize
+    }
+
+    fn match_beacons(&mut self, distance: Point3, s2: &Scanner) -> i32 {
+        let mut cnt = 0;
+        for b in self.beacons.iter_mut() {
+            *b -= distance;
+            if s2.beacons.contains(&(*b)) {
+                cnt += 1;
+            }
+        }
+        cnt
+    }
+
+    /// `self` turned every possible way.
+    fn rotate(&self) -> Vec<Scanner> {
+        Rotation::all()
+            .into_iter()
+            .map(|rotation| Scanner {
+                beacons: self.beacons.iter().map(|b| rotation * *b).collect(),
+                ..Scanner::new(self.index)
+            })
+            .collect()
+    }
+
+    fn common_beacons(s1: &Scanner, s2: &Scanner) -> (i32, Point3) {
+        let mut cnt = 0;
+        for b1 in &s1.beacons {
+            for b2 in &s2.beacons {
+                let distance = *b1 - *b2;
+                let mut s = s1.clone();
+                cnt = std::cmp::max(cnt, s.match_beacons(distance, s2));
+                if cnt >= 12 {
+                    return (cnt, distance);
+                }
+            }
+        }
+        (0, Point3::ORIGIN)
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Every scanner with its beacons turned to the orientation of scanner 0
+    /// and its position relative to it. Both parts need the scanners placed,
+    /// so the expensive matching is done once here.
+    type Input = Vec<Scanner>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut scanners = vec![];
+        let mut headers = vec![];
+        let mut scanner = Scanner::new(0);
+        let mut cnt = 0;
+        for line in s.lines() {
+            if line.contains("---") {
+                headers.push(line);
+                continue;
+            }
+            if line.is_empty() {
+                scanners.push(scanner);
+                cnt += 1;
+                scanner = Scanner::new(cnt);
+            } else {
+                scanner.beacons.push(parse_beacon(s, line)?);
+            }
+        }
+        scanners.push(scanner);
+
+        let mut scanner_queue = vec![scanners[0].clone()];
+        let mut visited = vec![];
+
+        loop {
+            if scanner_queue.len() == scanners.len() {
+                break;
+            }
+
+            let mut new_scanner = vec![];
+            for s in &scanner_queue {
+                if scanner_visited(&visited, s.index) {
+                    continue;
+                }
+                for s2 in &scanners {
+                    // placed already, maybe by another scanner of this round
+                    if scanner_queue.contains(s2) {
+                        continue;
+                    }
+                    let mut rotate_scanners = s2.rotate();
+                    for rs in &mut rotate_scanners {
+                        let (comm, distance) = Scanner::common_beacons(s, rs);
+                        if comm >= 12 {
+                            trace!(scanner = rs.index, by = s.index, common = comm, "matched");
+                            if !scanner_visited(&visited, rs.index) && !new_scanner.contains(rs) {
+                                rs.position = distance + s.position;
+                                debug!(scanner = rs.index, by = s.index, position = ?rs.position, "placed");
+                                new_scanner.push(rs.clone());
+                            }
+                            break;
+                        }
+                    }
+                }
+                visited.push(s.index);
+            }
+            // every placed scanner was tried against the rest, nothing is
+            // left that could place another one
+            if new_scanner.is_empty() {
+                let lost = scanners
+                    .iter()
+                    .find(|s| !scanner_queue.contains(s))
+                    .unwrap();
+                return Err(match headers.get(lost.index) {
+                    Some(header) => ParseError::at(s, header, "scanner overlaps no other one"),
+                    None => ParseError::end(s, "a scanner overlaps no other one"),
+                });
+            }
+            for s in new_scanner {
+                scanner_queue.push(s);
+            }
+        }
+        Ok(scanner_queue)
+    }
+
+    fn part1(scanners: &Self::Input) -> usize {
+        let mut set = HashSet::new();
+        for s in scanners {
+            for b in &s.beacons {
+                set.insert(*b + s.position);
+            }
+        }
+        set.len()
+    }
+
+    fn part2(scanners: &Self::Input) -> usize {
+        let mut manhattan_distance = 0;
+        for (i, s1) in scanners.iter().enumerate() {
+            for (j, s2) in scanners.iter().enumerate() {
+                if j > i {
+                    manhattan_distance =
+                        std::cmp::max(manhattan_distance, Scanner::manhattan_distance(s1, s2));
+                }
+            }
+        }
+        manhattan_distance
+    }
+}
+
+fn scanner_visited(visited: &Vec<usize>, scanner: usize) -> bool {
+    for index in visited {
+        if *index == scanner {
+            return true;
+        }
+    }
+    false
+}
+
+impl Generate for Solver {
+    /// `size` scanners (at least 2), each sharing 12 beacons with one placed
+    /// before it and seeing everything within 1000 on every axis.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const RANGE: i64 = 1000;
+        let mut scanners = vec![[0i64; 3]];
+        let mut beacons = HashSet::new();
+        let mut scatter = |rng: &mut Rng, low: [i64; 3], high: [i64; 3], n: usize| {
+            for _ in 0..n {
+                beacons.insert([0, 1, 2].map(|a| rng.range(low[a]..=high[a])));
+            }
+        };
+        scatter(rng, [-RANGE; 3], [RANGE; 3], 14);
+        for _ in 1..size.max(2) {
+            let parent = *rng.pick(&scanners);
+            // far enough apart that few other scanners see the same beacons
+            let scanner = parent.map(|p| p + rng.range(600..=1200) * rng.pick(&[-1, 1]));
+            let low = [0, 1, 2].map(|a| parent[a].max(scanner[a]) - RANGE);
+            let high = [0, 1, 2].map(|a| parent[a].min(scanner[a]) + RANGE);
+            scatter(rng, low, high, 12);
+            scatter(
+                rng,
+                scanner.map(|s| s - RANGE),
+                scanner.map(|s| s + RANGE),
+                8,
+            );
+            scanners.push(scanner);
+        }
+        let rotations = Rotation::all();
+        scanners
+            .iter()
+            .enumerate()
+            .map(|(i, scanner)| {
+                let rotation = *rng.pick(&rotations);
+                let mut seen = beacons
+                    .iter()
+                    .filter(|b| (0..3).all(|a| (b[a] - scanner[a]).abs() <= RANGE))
+                    .map(|b| {
+                        let [x, y, z] = [0, 1, 2].map(|a| b[a] - scanner[a]);
+                        let Point3 { x, y, z } = rotation * Point3::new(x, y, z);
+                        format!("{x},{y},{z}")
+                    })
+                    .collect::<Vec<_>>();
+                seen.sort_unstable();
+                rng.shuffle(&mut seen);
+                format!("--- scanner {i} ---\n{}", seen.join("\n"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
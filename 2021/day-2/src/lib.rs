@@ -0,0 +1,81 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, split_once, ParseError},
+    solution::Solution,
+};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<(String, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut cmd = vec![];
+        for line in s.lines() {
+            let (op, data) = split_once(s, line, " ")?;
+            if !["forward", "up", "down"].contains(&op) {
+                return Err(ParseError::at(s, op, "expected forward, up or down"));
+            }
+            cmd.push((op.to_string(), number(s, data)?));
+        }
+        Ok(cmd)
+    }
+
+    fn part1(cmd: &Self::Input) -> i32 {
+        let mut hoz = 0;
+        let mut depth = 0;
+        for (k, v) in cmd {
+            match k.as_str() {
+                "forward" => hoz += v,
+                "up" => depth -= v,
+                "down" => depth += v,
+                _ => unreachable!(),
+            };
+        }
+        hoz * depth
+    }
+
+    fn part2(cmd: &Self::Input) -> i32 {
+        let mut aim = 0;
+        let mut hoz = 0;
+        let mut depth = 0;
+        for (k, v) in cmd {
+            match k.as_str() {
+                "forward" => {
+                    hoz += v;
+                    depth += aim * v;
+                }
+                "up" => aim -= v,
+                "down" => aim += v,
+                _ => unreachable!(),
+            };
+        }
+        hoz * depth
+    }
+}
+
+impl Generate for Solver {
+    /// `size` commands, never aiming up out of the water.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut aim = 0;
+        (0..size)
+            .map(|_| {
+                let n = rng.range(1..=9);
+                let op = match rng.below(3) {
+                    0 => "forward",
+                    1 if aim >= n => "up",
+                    _ => "down",
+                };
+                match op {
+                    "up" => aim -= n,
+                    "down" => aim += n,
+                    _ => (),
+                }
+                format!("{op} {n}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_common::{
+    frames::{Frame, Recorder, Visualise},
+    generate::{Generate, Rng},
+    grid::{Cell, Grid},
+    parse::{invalid_char, split_once, ParseError},
+    solution::Solution,
+};
+
+#[derive(Cell, Debug, Clone, Copy, PartialEq, Eq)]
+enum Pixel {
+    #[cell('.')]
+    Dark,
+    #[cell('#')]
+    Light,
+}
+
+#[derive(Clone)]
+pub struct Image {
+    pixels: Grid<Pixel>,
+    alg: Vec<Pixel>,
+}
+
+impl Image {
+    fn new(pixels: Grid<Pixel>, alg: Vec<Pixel>) -> Self {
+        Self { pixels, alg }
+    }
+
+    fn frame(&self) -> Frame {
+        self.pixels.map(Pixel::symbol)
+    }
+
+    fn get_alg_pixel(&self, row: isize, col: isize, iter: usize) -> Pixel {
+        let infinity_pixel = self.get_infinity_pixel(iter);
+        let mut alg_index = 0;
+        for i in (row - 1)..=(row + 1) {
+            for j in (col - 1)..=(col + 1) {
+                let pixel = self.pixels.get_checked(i, j).unwrap_or(&infinity_pixel);
+                alg_index = alg_index << 1 | (*pixel == Pixel::Light) as usize;
+            }
+        }
+        self.alg[alg_index]
+    }
+
+    fn get_infinity_pixel(&self, iter: usize) -> Pixel {
+        if self.alg[0] == Pixel::Dark {
+            return Pixel::Dark;
+        }
+        if iter % 2 == 1 {
+            self.alg[0]
+        } else {
+            *self.alg.last().unwrap()
+        }
+    }
+
+    fn expand(&mut self, iter: usize) {
+        let infinity_pixel = self.get_infinity_pixel(iter);
+        self.pixels = Grid::from_fn(
+            self.pixels.height() + 2,
+            self.pixels.width() + 2,
+            |(row, col)| {
+                *self
+                    .pixels
+                    .get_checked(row as isize - 1, col as isize - 1)
+                    .unwrap_or(&infinity_pixel)
+            },
+        );
+    }
+
+    fn enhancement(&self, iter: usize) -> Self {
+        let pixels = Grid::from_fn(self.pixels.height(), self.pixels.width(), |(i, j)| {
+            self.get_alg_pixel(i as isize, j as isize, iter)
+        });
+        Self {
+            pixels,
+            alg: self.alg.clone(),
+        }
+    }
+
+    fn count_lit_pixel(&self) -> usize {
+        self.pixels
+            .cells()
+            .iter()
+            .filter(|pixel| **pixel == Pixel::Light)
+            .count()
+    }
+}
+
+/// Count the lit pixels after `times` enhancements.
+fn enhance(image: &Image, times: usize, recorder: &mut Recorder) -> usize {
+    let mut image = image.clone();
+    recorder.record(|| image.frame());
+    (0..times).for_each(|iter| {
+        image.expand(iter);
+        image = image.enhancement(iter);
+        recorder.record(|| image.frame());
+    });
+    image.count_lit_pixel()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let (alg, image) = split_once(s, s, "\n\n")?;
+        if let Some(c) = invalid_char(alg, |c| Pixel::try_from(c).is_ok()) {
+            return Err(ParseError::at(s, c, "expected . or #"));
+        }
+        if alg.len() != 512 {
+            return Err(ParseError::at(s, alg, "expected 512 pixels of algorithm"));
+        }
+        let alg_vec = alg.chars().map(|c| Pixel::try_from(c).unwrap()).collect();
+        // build image
+        let pixels = Grid::parse_cells(image).map_err(|e| ParseError::from(e).within(s, image))?;
+        Ok(Image::new(pixels, alg_vec))
+    }
+
+    fn part1(image: &Self::Input) -> usize {
+        enhance(image, 2, &mut Recorder::off())
+    }
+
+    fn part2(image: &Self::Input) -> usize {
+        enhance(image, 50, &mut Recorder::off())
+    }
+}
+
+impl Visualise for Solver {
+    /// The image growing over the 50 enhancements of part 2.
+    fn visualise(image: &Self::Input, recorder: &mut Recorder) {
+        enhance(image, 50, recorder);
+    }
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` image, with an algorithm that may light the
+    /// infinite background but then always darkens it again.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+        let mut algorithm = (0..512).map(|_| pixel(rng)).collect::<Vec<_>>();
+        if algorithm[0] == '#' {
+            algorithm[511] = '.';
+        }
+        let image = (0..size.max(1))
+            .map(|_| (0..size.max(1)).map(|_| pixel(rng)).collect::<String>())
+            .collect::<Vec<_>>();
+        format!(
+            "{}\n\n{}",
+            algorithm.into_iter().collect::<String>(),
+            image.join("\n")
+        )
+    }
+}
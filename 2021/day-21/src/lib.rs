@@ -0,0 +1,184 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    memo::Memo,
+    parse::{next, number, split_once, ParseError},
+    solution::Solution,
+};
+use tracing::debug;
+
+struct Dice {
+    sided: usize,
+    rolled: usize,
+}
+
+impl Dice {
+    fn roll(&mut self) -> usize {
+        let mut step = 0;
+        for _ in 0..3 {
+            step += self.rolled % self.sided + 1;
+            self.rolled += 1;
+        }
+        step
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Player {
+    pos: usize,
+    score: usize,
+    win_score: usize,
+}
+
+impl Player {
+    fn new(pos: usize) -> Self {
+        Self {
+            pos,
+            score: 0,
+            win_score: 0,
+        }
+    }
+    fn win(&self) -> bool {
+        self.score >= self.win_score
+    }
+
+    fn add_score(&mut self, score: usize) {
+        self.score += score;
+        self.pos = score;
+    }
+}
+
+const TRACK_LEN: usize = 10;
+fn track_score(start: usize, step: usize) -> usize {
+    // map 1..10 to 0..9 for mod
+    (start - 1 + step) % TRACK_LEN + 1
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Starting positions of both players.
+    type Input = (Player, Player);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = s.lines();
+        let mut player = |n: usize| -> Result<Player, ParseError> {
+            let line = next(s, s, &mut lines, &format!("player {n} starting position"))?;
+            let (_, pos) = split_once(s, line, ": ")?;
+            let n = number::<usize>(s, pos)?;
+            if !(1..=TRACK_LEN).contains(&n) {
+                return Err(ParseError::at(
+                    s,
+                    pos,
+                    format!("expected a position 1 to {TRACK_LEN}"),
+                ));
+            }
+            Ok(Player::new(n))
+        };
+        Ok((player(1)?, player(2)?))
+    }
+
+    fn part1(&(player1, player2): &Self::Input) -> usize {
+        part1(player1, player2)
+    }
+
+    fn part2(&(player1, player2): &Self::Input) -> usize {
+        part2(player1, player2)
+    }
+}
+
+/// Wins of each player from a state: whose turn it is, then the position
+/// and score of both players.
+type WinMemo = Memo<(usize, usize, usize, usize, usize), (usize, usize)>;
+
+fn win_universe(round: usize, p1: &Player, p2: &Player, memo: &mut WinMemo) -> (usize, usize) {
+    let key = (round, p1.pos, p2.pos, p1.score, p2.score);
+    memo.solve(key, |memo| {
+        if p1.win() {
+            return (1, 0);
+        }
+        if p2.win() {
+            return (0, 1);
+        }
+
+        let mut p1_win = 0;
+        let mut p2_win = 0;
+        for i in 1..=3 {
+            for j in 1..=3 {
+                for k in 1..=3 {
+                    let mut p = if round == 0 { *p1 } else { *p2 };
+                    let score = track_score(p.pos, i + j + k);
+                    p.add_score(score);
+                    let (w1, w2) = if round == 0 {
+                        win_universe(1 - round, &p, p2, memo)
+                    } else {
+                        win_universe(1 - round, p1, &p, memo)
+                    };
+                    p1_win += w1;
+                    p2_win += w2;
+                }
+            }
+        }
+        (p1_win, p2_win)
+    })
+}
+
+fn part2(mut p1: Player, mut p2: Player) -> usize {
+    p1.win_score = 21;
+    p2.win_score = 21;
+
+    let mut memo = Memo::new();
+    let (w1, w2) = win_universe(0, &p1, &p2, &mut memo);
+    debug!(memo = %memo.stats(), "counted the universes");
+    std::cmp::max(w1, w2)
+}
+
+fn part1(mut p1: Player, mut p2: Player) -> usize {
+    p1.win_score = 1000;
+    p2.win_score = 1000;
+    let mut dice = Dice {
+        sided: 100,
+        rolled: 0,
+    };
+
+    loop {
+        p1.add_score(track_score(p1.pos, dice.roll()));
+        // the game ends as soon as one player wins, the other does not move
+        if p1.win() {
+            return p2.score * dice.rolled;
+        }
+        p2.add_score(track_score(p2.pos, dice.roll()));
+        if p2.win() {
+            return p1.score * dice.rolled;
+        }
+    }
+}
+
+impl Generate for Solver {
+    /// Starting positions of the two players, `size` being ignored.
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        (1..=2)
+            .map(|player| format!("Player {player} starting position: {}", rng.range(1..=10)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        // player 1 wins first, player 2 must not get one more turn
+        assert_eq!(Solver::part1(&Solver::parse(SAMPLE).unwrap()), 739785);
+    }
+    #[test]
+    fn test_part2_sample() {
+        assert_eq!(
+            Solver::part2(&Solver::parse(SAMPLE).unwrap()),
+            444356092776315
+        );
+    }
+}
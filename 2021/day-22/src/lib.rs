@@ -0,0 +1,190 @@
+use aoc_common::{
+    compress::{Axis, Compressed},
+    generate::{Generate, Rng},
+    interval::{Cuboid, Interval},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+use regex::Regex;
+use tracing::{debug, trace};
+
+#[cfg(test)]
+mod reference;
+
+#[derive(Debug, Clone, Copy)]
+enum CubeState {
+    On,
+    Off,
+}
+
+impl CubeState {
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            _ => Err(ParseError::at(input, s, "expected on or off")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Cube {
+    state: CubeState,
+    cuboid: Cuboid<3>,
+}
+
+impl Cube {
+    fn new(state: CubeState, cuboid: Cuboid<3>) -> Self {
+        Self { state, cuboid }
+    }
+}
+
+/// Volume lit once every step of `cubes` is applied, one slab of the
+/// reactor across the x axis at a time, so that only a plane of cells is
+/// held at once.
+fn count_on(cubes: &[Cube]) -> i64 {
+    let xs = Axis::new(cubes.iter().flat_map(|cube| {
+        let x = cube.cuboid.axes[0];
+        [x.start, x.end]
+    }));
+    debug!(cubes = cubes.len(), slabs = xs.len(), "sliced the reactor");
+    (0..xs.len())
+        .map(|slab| {
+            let x = xs.interval(slab);
+            let steps = cubes
+                .iter()
+                .filter(|cube| cube.cuboid.axes[0].covers(&x))
+                .map(|cube| {
+                    let [_, y, z] = cube.cuboid.axes;
+                    (cube.state, Cuboid::new([y, z]))
+                })
+                .collect::<Vec<_>>();
+            xs.width(slab) * area_on(&steps)
+        })
+        .sum()
+}
+
+/// Area lit once every step of `steps` is applied to a slab, cut only at
+/// the faces of the steps through it.
+fn area_on(steps: &[(CubeState, Cuboid<2>)]) -> i64 {
+    let plane = Compressed::of_cuboids(steps.iter().map(|(_, rect)| rect));
+    let mut lit = vec![false; plane.len()];
+    for (state, rect) in steps {
+        let on = matches!(state, CubeState::On);
+        for cell in plane.cells_in(rect) {
+            lit[plane.index(cell)] = on;
+        }
+    }
+    plane
+        .cells()
+        .filter(|cell| lit[plane.index(*cell)])
+        .map(|cell| plane.volume(cell))
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Cube>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let reg =
+            Regex::new(r"^(\w+) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
+                .unwrap();
+
+        let mut cubes = vec![];
+        for line in s.lines() {
+            let cap = reg
+                .captures(line)
+                .ok_or_else(|| ParseError::at(s, line, "expected on|off x=a..b,y=c..d,z=e..f"))?;
+            let token = |i: usize| cap.get(i).unwrap().as_str();
+            let mut axes = [Interval::default(); 3];
+            for (a, axis) in axes.iter_mut().enumerate() {
+                let (first, last) = (token(2 * a + 2), token(2 * a + 3));
+                *axis = Interval::inclusive(number(s, first)?, number(s, last)?)
+                    .ok_or_else(|| ParseError::at(s, last, "expected a number below i64::MAX"))?;
+            }
+            let cuboid = Cuboid::new(axes);
+            let state = CubeState::parse(s, cap.get(1).unwrap().as_str())?;
+            trace!(?state, ?cuboid, "reboot step");
+            cubes.push(Cube::new(state, cuboid));
+        }
+        Ok(cubes)
+    }
+
+    fn part1(cubes: &Self::Input) -> i64 {
+        // the initialization procedure only looks at -50..=50 on every axis
+        let area = Cuboid::new([Interval::new(-50, 51); 3]);
+        let cubes_part1 = cubes
+            .iter()
+            .filter(|cube| area.covers(&cube.cuboid))
+            .copied()
+            .collect::<Vec<_>>();
+        count_on(&cubes_part1)
+    }
+
+    fn part2(cubes: &Self::Input) -> i64 {
+        count_on(cubes)
+    }
+}
+
+impl Generate for Solver {
+    /// `size` reboot steps, the first 20 of them in the initialization area.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|i| {
+                let (reach, extent) = if i < 20 {
+                    (50, 10..=50)
+                } else {
+                    (100_000, 1000..=50_000)
+                };
+                let state = if i == 0 || rng.chance(0.7) {
+                    "on"
+                } else {
+                    "off"
+                };
+                let [x, y, z] = [0; 3].map(|_| {
+                    let extent = rng.range(extent.clone());
+                    let low = rng.range(-reach..=reach - extent);
+                    format!("{low}..{}", low + extent)
+                });
+                format!("{state} x={x},y={y},z={z}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_on_reference() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let input = (0..rng.below(8) + 1)
+                .map(|_| {
+                    let state = rng.pick(&["on", "off"]);
+                    let [x, y, z] = [0; 3].map(|_| {
+                        let low = rng.range(-10..=10);
+                        format!("{low}..{}", low + rng.range(0..=6))
+                    });
+                    format!("{state} x={x},y={y},z={z}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let cubes = Solver::parse(&input).unwrap();
+            assert_eq!(count_on(&cubes), reference::count_on(&cubes), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_largest_coordinate() {
+        let e = Solver::parse("on x=0..1,y=0..9223372036854775807,z=0..1").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 16));
+        assert_eq!(e.message, "expected a number below i64::MAX");
+    }
+}
@@ -0,0 +1,25 @@
+//! The reboot steps applied cube by cube, to check the compressed grid
+//! against.
+
+use std::collections::HashSet;
+
+use crate::{Cube, CubeState};
+
+/// Cubes lit once every step of `cubes` is applied.
+pub fn count_on(cubes: &[Cube]) -> i64 {
+    let mut lit = HashSet::new();
+    for cube in cubes {
+        let [xs, ys, zs] = cube.cuboid.axes;
+        for x in xs.start..xs.end {
+            for y in ys.start..ys.end {
+                for z in zs.start..zs.end {
+                    match cube.state {
+                        CubeState::On => lit.insert((x, y, z)),
+                        CubeState::Off => lit.remove(&(x, y, z)),
+                    };
+                }
+            }
+        }
+    }
+    lit.len() as i64
+}
@@ -0,0 +1,239 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    memo::Memo,
+    parse::{next, token, ParseError},
+    solution::Solution,
+};
+use tracing::debug;
+
+type Ret = (bool, i64);
+
+#[derive(Debug)]
+enum OpType {
+    Inp,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+impl OpType {
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        match s {
+            "inp" => Ok(Self::Inp),
+            "add" => Ok(Self::Add),
+            "mul" => Ok(Self::Mul),
+            "div" => Ok(Self::Div),
+            "mod" => Ok(Self::Mod),
+            "eql" => Ok(Self::Eql),
+            _ => Err(ParseError::at(input, s, "expected an instruction")),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Variable {
+    W(u8),
+    X(u8),
+    Y(u8),
+    Z(u8),
+    N(i64),
+}
+
+impl Variable {
+    /// A register, or a number when `number_ok`.
+    fn parse(input: &str, s: &str, number_ok: bool) -> Result<Self, ParseError> {
+        match s {
+            "w" => Ok(Self::W(0)),
+            "x" => Ok(Self::X(1)),
+            "y" => Ok(Self::Y(2)),
+            "z" => Ok(Self::Z(3)),
+            _ if number_ok => token(input, s, "a register or a number").map(Self::N),
+            _ => Err(ParseError::at(input, s, "expected a register w, x, y or z")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Op {
+    typ: OpType,
+    value1: Variable,
+    value2: Variable,
+}
+
+/// Where running the ops leads from an op index and the registers
+/// `[w, x, y, z]`.
+type ResolveMemo = Memo<(usize, [i64; 4]), Ret>;
+
+/// Run `ops` from `op_index` with the registers `[w, x, y, z]` in `store`:
+/// whether some digits bring z to 0, and those digits from the last one.
+fn resolve(
+    ops: &[Op],
+    range: &[i64],
+    memo: &mut ResolveMemo,
+    op_index: usize,
+    store: [i64; 4],
+) -> Ret {
+    memo.solve((op_index, store), |memo| {
+        resolve_op(ops, range, memo, op_index, store)
+    })
+}
+
+fn resolve_op(
+    ops: &[Op],
+    range: &[i64],
+    memo: &mut ResolveMemo,
+    op_index: usize,
+    mut store: [i64; 4],
+) -> Ret {
+    if op_index >= ops.len() {
+        return (store[3] == 0, 0);
+    }
+    let [_, x, y, z] = store;
+    let store_get = |s: &Variable, store: &[i64; 4]| -> i64 {
+        use Variable::*;
+        match s {
+            W(n) | X(n) | Y(n) | Z(n) => store[*n as usize],
+            N(n) => *n,
+        }
+    };
+
+    let op = &ops[op_index];
+    let v1 = store_get(&op.value1, &store);
+    let v2 = store_get(&op.value2, &store);
+
+    let mut store_set = |s: &Variable, v: i64| {
+        use Variable::*;
+        match s {
+            W(n) | X(n) | Y(n) | Z(n) => store[*n as usize] = v,
+            _ => unreachable!(),
+        }
+    };
+
+    match op.typ {
+        OpType::Inp => {
+            for next_w in range {
+                let res = resolve(ops, range, memo, op_index + 1, [*next_w, x, y, z]);
+                if res.0 {
+                    return (res.0, next_w + res.1 * 10);
+                }
+            }
+            return (false, 0);
+        }
+        OpType::Add => {
+            store_set(&op.value1, v1 + v2);
+        }
+        OpType::Mul => {
+            store_set(&op.value1, v1 * v2);
+        }
+        OpType::Div => {
+            if v2 == 0 {
+                return (false, 0);
+            }
+            store_set(&op.value1, v1 / v2);
+        }
+        OpType::Mod => {
+            if v1 < 0 || v2 <= 0 {
+                return (false, 0);
+            }
+            store_set(&op.value1, v1 % v2);
+        }
+        OpType::Eql => {
+            store_set(&op.value1, if v1 == v2 { 1 } else { 0 });
+        }
+    }
+    resolve(ops, range, memo, op_index + 1, store)
+}
+
+/// The model number accepted by the MONAD program, trying each digit in the
+/// order of `digit_range`.
+fn model_number(ops: &[Op], digit_range: &[i64]) -> String {
+    // keyed on the registers only, so one memo per digit order
+    let mut memo = Memo::bounded(10_000_000);
+    // resolve() accumulates the digits from the last one
+    let (_, digits) = resolve(ops, digit_range, &mut memo, 0, [0; 4]);
+    debug!(memo = %memo.stats(), "searched the model numbers");
+    digits.to_string().chars().rev().collect::<String>()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Op>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut ops = vec![];
+        for line in s.lines() {
+            let line = line.trim();
+            let mut op = line.split(' ');
+            let typ = OpType::parse(s, next(s, line, &mut op, "an instruction")?)?;
+            let value1 = Variable::parse(s, next(s, line, &mut op, "a register")?, false)?;
+            let value2 = match typ {
+                OpType::Inp => Variable::N(0),
+                _ => Variable::parse(s, next(s, line, &mut op, "a register or a number")?, true)?,
+            };
+            if let Some(extra) = op.next() {
+                return Err(ParseError::at(s, extra, "expected the end of the line"));
+            }
+            ops.push(Op {
+                typ,
+                value1,
+                value2,
+            });
+        }
+        Ok(ops)
+    }
+
+    fn part1(ops: &Self::Input) -> String {
+        model_number(ops, &(1..10).rev().collect::<Vec<i64>>())
+    }
+
+    fn part2(ops: &Self::Input) -> String {
+        model_number(ops, &(1..10).collect::<Vec<i64>>())
+    }
+}
+
+impl Generate for Solver {
+    /// A MONAD of `size` digit blocks (an even number from 2 to 18, for the
+    /// model number to fit an i64), whose blocks push a digit on z or pop one
+    /// that some digit pair can match, as in the puzzle's. The search for the
+    /// largest and smallest model numbers backtracks over every later digit
+    /// whenever a digit it tries fails, so only the pairs pushed in the last
+    /// few blocks need digits that differ. The others match the same digits,
+    /// the first the search tries, which keeps it to about as many steps as
+    /// there are blocks.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // the search is over in about a second even when they all differ
+        const DIFFERING: usize = 6;
+        let digits = (size.clamp(2, 18) / 2) * 2;
+        let mut pushed = vec![];
+        let mut blocks = vec![];
+        for i in 0..digits {
+            let remaining = digits - i;
+            let push = pushed.is_empty() || (pushed.len() + 2 <= remaining && rng.chance(0.5));
+            let offset = rng.range(1..=16);
+            let (divisor, check) = if push {
+                pushed.push((i, offset));
+                (1, rng.range(10..=15))
+            } else {
+                // the popped digit plus its offset has to equal this digit
+                let (at, popped) = pushed.pop().unwrap();
+                let difference = if at + DIFFERING >= digits {
+                    rng.range(-8..=8)
+                } else {
+                    0
+                };
+                (26, difference - popped)
+            };
+            blocks.push(format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {divisor}\nadd x {check}\n\
+                 eql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\n\
+                 mul y 0\nadd y w\nadd y {offset}\nmul y x\nadd z y"
+            ));
+        }
+        blocks.join("\n")
+    }
+}
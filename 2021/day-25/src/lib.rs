@@ -0,0 +1,186 @@
+use aoc_common::{
+    cycle,
+    frames::{Frame, Recorder, Visualise},
+    generate::{Generate, Rng},
+    grid::{Cell, Grid},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Cell, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    #[cell('.')]
+    Empty,
+    #[cell('>')]
+    East,
+    #[cell('v')]
+    South,
+}
+
+#[derive(Debug)]
+struct Land {
+    move_steps_cnt: usize,
+    cells: Grid<State>,
+}
+
+impl Land {
+    fn new(cells: Grid<State>) -> Self {
+        Self {
+            move_steps_cnt: 0,
+            cells,
+        }
+    }
+
+    fn move_herd(&mut self, herd: State, step: (isize, isize)) -> bool {
+        let mut move_steps = vec![];
+        for (pos, cell) in self.cells.iter() {
+            let next = self.cells.wrapping_offset(pos, step);
+            if *cell == herd && self.cells[next] == State::Empty {
+                move_steps.push((pos, State::Empty));
+                move_steps.push((next, herd));
+            }
+        }
+
+        for (pos, state) in &move_steps {
+            self.cells[*pos] = *state
+        }
+        !move_steps.is_empty()
+    }
+
+    fn move_east(&mut self) -> bool {
+        self.move_herd(State::East, (0, 1))
+    }
+
+    fn move_south(&mut self) -> bool {
+        self.move_herd(State::South, (1, 0))
+    }
+
+    fn move_step(&mut self) -> bool {
+        self.move_steps_cnt += 1;
+        let e = self.move_east();
+        let s = self.move_south();
+        e || s
+    }
+
+    fn frame(&self) -> Frame {
+        self.cells.map(State::symbol)
+    }
+
+    /// Move until the herds are stuck, returning the number of steps taken,
+    /// or `None` when some of them keep going round the trench forever.
+    fn settle(&self, recorder: &mut Recorder) -> Option<usize> {
+        recorder.record(|| self.frame());
+        // the herds with whether anyone moved on the step that led to them
+        let watched = |(cells, _): &(Grid<State>, bool)| {
+            let mut land = Land::new(cells.clone());
+            let moved = land.move_step();
+            if moved {
+                recorder.record(|| land.frame());
+            }
+            (land.cells, moved)
+        };
+        let stuck = cycle::until((self.cells.clone(), true), watched, |(_, moved)| !moved)?;
+        Some(stuck.0 as usize)
+    }
+}
+
+/// The herds one step after `cells`.
+fn step(cells: &Grid<State>) -> Grid<State> {
+    let mut land = Land::new(cells.clone());
+    land.move_step();
+    land.cells
+}
+
+/// The herds after `n` steps, however many: once stuck they stay so.
+pub fn herds_after(cells: &Grid<State>, n: u64) -> Grid<State> {
+    cycle::nth(cells.clone(), step, n)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<State>;
+    type Answer1 = usize;
+    /// The last day only has one puzzle.
+    type Answer2 = &'static str;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let cells = Grid::parse_cells(s)?;
+        if Land::new(cells.clone())
+            .settle(&mut Recorder::off())
+            .is_none()
+        {
+            return Err(ParseError::end(s, "expected herds that end up stuck"));
+        }
+        Ok(cells)
+    }
+
+    fn part1(cells: &Self::Input) -> usize {
+        Land::new(cells.clone())
+            .settle(&mut Recorder::off())
+            .expect("parse checks that the herds get stuck")
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "-"
+    }
+}
+
+impl Visualise for Solver {
+    /// The herds moving until they are stuck.
+    fn visualise(cells: &Self::Input, recorder: &mut Recorder) {
+        Land::new(cells.clone()).settle(recorder);
+    }
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` trench (at least 2). A full row of east-facing and a
+    /// full column of south-facing sea cucumbers never move, so the rest
+    /// always end up stuck against them.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        let (wall_row, wall_col) = (rng.below(side), rng.below(side));
+        (0..side)
+            .map(|row| {
+                (0..side)
+                    .map(|col| match rng.below(3) {
+                        _ if row == wall_row => '>',
+                        _ if col == wall_col => 'v',
+                        0 => '>',
+                        1 => 'v',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_herds_after() {
+        let cells = Solver::parse(include_str!("sample")).unwrap();
+        // the step nobody moves on leaves them as the one before
+        let stuck = Solver::part1(&cells) as u64 - 1;
+        let end = herds_after(&cells, 1_000_000_000_000);
+        assert_eq!(herds_after(&cells, stuck), end);
+        assert_ne!(herds_after(&cells, stuck - 1), end);
+        let mut land = Land::new(cells.clone());
+        (0..10).for_each(|_| {
+            land.move_step();
+        });
+        assert_eq!(herds_after(&cells, 10), land.cells);
+    }
+
+    #[test]
+    fn test_never_stuck() {
+        let e = Solver::parse(">.\n..").unwrap_err();
+        assert_eq!(e.message, "expected herds that end up stuck");
+    }
+}
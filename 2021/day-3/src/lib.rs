@@ -0,0 +1,110 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{invalid_char, ParseError},
+    solution::Solution,
+};
+
+fn get_zero_one_cnt_at_index<'a>(v: &Vec<&'a str>, index: usize) -> (Vec<&'a str>, Vec<&'a str>) {
+    let mut zero = vec![];
+    let mut one = vec![];
+    for line in v {
+        if index >= line.len() {
+            panic!("index overflow");
+        }
+        match line.chars().nth(index).unwrap() {
+            '0' => zero.push(*line),
+            '1' => one.push(*line),
+            _ => unreachable!(),
+        }
+    }
+    (zero, one)
+}
+
+/// Keep filtering the report on each bit until one number is left, `keep_one`
+/// decides from the count of zeros and ones whether the ones are kept.
+fn rating(report: &[String], keep_one: impl Fn(usize, usize) -> bool) -> i32 {
+    let mut v = report.iter().map(|l| l.as_str()).collect::<Vec<_>>();
+    let mut i = 0;
+    // numbers repeated in the report are left together once every bit is seen
+    while v.len() > 1 && i < v[0].len() {
+        let (zero, one) = get_zero_one_cnt_at_index(&v, i);
+        v = if keep_one(zero.len(), one.len()) {
+            one
+        } else {
+            zero
+        };
+        i += 1;
+    }
+    i32::from_str_radix(v.first().unwrap(), 2).unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(lines: &str) -> Result<Self::Input, ParseError> {
+        if lines.trim().is_empty() {
+            return Err(ParseError::end(lines, "expected a diagnostic report"));
+        }
+        let width = lines.lines().next().map_or(0, str::len);
+        lines
+            .lines()
+            .map(|line| {
+                if let Some(c) = invalid_char(line, |c| c == '0' || c == '1') {
+                    return Err(ParseError::at(lines, c, "expected a bit"));
+                }
+                if line.len() != width {
+                    return Err(ParseError::at(
+                        lines,
+                        line,
+                        format!("expected {width} bits like the first line"),
+                    ));
+                }
+                Ok(line.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(report: &Self::Input) -> i32 {
+        let v = report.iter().map(|l| l.as_str()).collect::<Vec<_>>();
+        let line_len = v.first().map_or(0, |line| line.len());
+        let mut gamma = 0;
+        let mut epsilon = 0;
+        for i in 0..line_len {
+            let (zero, one) = get_zero_one_cnt_at_index(&v, i);
+            if zero.len() > one.len() {
+                gamma <<= 1;
+                epsilon = epsilon << 1 | 1;
+            } else {
+                gamma = gamma << 1 | 1;
+                epsilon <<= 1;
+            }
+        }
+        gamma * epsilon
+    }
+
+    fn part2(report: &Self::Input) -> i32 {
+        let oxygen = rating(report, |zero, one| one >= zero);
+        let co2 = rating(report, |zero, one| zero == 0 || (one > 0 && one < zero));
+        oxygen * co2
+    }
+}
+
+impl Generate for Solver {
+    /// `size` distinct numbers, as wide as needed to tell them apart but at
+    /// most 15 bits so that the answers fit (fewer numbers past 2^15).
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = (usize::BITS - size.leading_zeros() + 1).clamp(5, 15) as usize;
+        let mut numbers = (0..1usize << width).collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+        numbers.truncate(size.max(1));
+        numbers
+            .iter()
+            .map(|n| format!("{n:0width$b}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Cell {
+    data: i32,
+    marked: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    board: Vec<Cell>,
+    row: usize,
+    col: usize,
+    win: bool,
+}
+impl Board {
+    fn new() -> Self {
+        Self {
+            board: vec![],
+            row: 0,
+            col: 0,
+            win: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Boards {
+    boards: Vec<Board>,
+}
+
+impl Boards {
+    fn new() -> Self {
+        Self { boards: vec![] }
+    }
+}
+
+/// Score of every board in the order they win.
+fn play(numbers: &[i32], boards: &Boards) -> Vec<i32> {
+    let mut boards = boards.boards.clone();
+    let mut scores = vec![];
+    for n in numbers {
+        // mark board
+        for board in boards.iter_mut() {
+            if board.win {
+                continue;
+            }
+            for cell in board.board.iter_mut() {
+                if cell.data == *n {
+                    cell.marked = true;
+                }
+            }
+            // board win
+            if let Some(m) = board_win(board) {
+                board.win = true;
+                scores.push(n * m);
+            }
+        }
+    }
+    scores
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = (Vec<i32>, Boards);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut line_iter = s.lines();
+
+        // parse number line
+        let numbers_line = line_iter
+            .next()
+            .ok_or_else(|| ParseError::end(s, "expected the drawn numbers"))?;
+        let numbers = numbers_line
+            .split(',')
+            .map(|n| number(s, n))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // parse boards, split by blank lines
+        let mut boards = Boards::new();
+        let mut board = Board::new();
+        let mut first_row = "";
+        for line in line_iter.chain([""]) {
+            if line.trim().is_empty() {
+                if !board.board.is_empty() {
+                    board.row = board.board.len() / board.col;
+                    if board.row != board.col {
+                        return Err(ParseError::at(
+                            s,
+                            first_row,
+                            format!("expected a square board of {} rows", board.col),
+                        ));
+                    }
+                    boards.boards.push(board);
+                    board = Board::new();
+                }
+                continue;
+            }
+
+            let row = line
+                .split_ascii_whitespace()
+                .map(|n| {
+                    Ok(Cell {
+                        data: number(s, n)?,
+                        marked: false,
+                    })
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+            if board.board.is_empty() {
+                board.col = row.len();
+                first_row = line;
+            } else if row.len() != board.col {
+                return Err(ParseError::at(
+                    s,
+                    line,
+                    format!("expected {} numbers like the first row", board.col),
+                ));
+            }
+            board.board.extend(row);
+        }
+        if play(&numbers, &boards).is_empty() {
+            return Err(ParseError::end(
+                s,
+                "expected a board that wins with the drawn numbers",
+            ));
+        }
+        Ok((numbers, boards))
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> i32 {
+        *play(numbers, boards).first().unwrap()
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> i32 {
+        *play(numbers, boards).last().unwrap()
+    }
+}
+
+fn board_win(board: &Board) -> Option<i32> {
+    for i in 0..board.row {
+        let mut row_win = true;
+        let mut col_win = true;
+        for j in 0..board.col {
+            //row
+            let cell = board.board.get(i * board.col + j).unwrap();
+            if !cell.marked {
+                row_win = false;
+            }
+            //col
+            let cell = board.board.get(j * board.col + i).unwrap();
+            if !cell.marked {
+                col_win = false;
+            }
+            if !row_win && !col_win {
+                break;
+            }
+        }
+        if row_win || col_win {
+            let mut sum = 0;
+            for cell in &board.board {
+                if !cell.marked {
+                    sum += cell.data;
+                }
+            }
+            return Some(sum);
+        }
+    }
+    None
+}
+
+impl Generate for Solver {
+    /// `size` boards, every number being drawn so that they all win.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut pool = (0..size.max(100) as i32).collect::<Vec<_>>();
+        rng.shuffle(&mut pool);
+        let mut input = pool
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        for _ in 0..size.max(1) {
+            rng.shuffle(&mut pool);
+            input.push('\n');
+            for row in pool[..25].chunks(5) {
+                let row = row.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>();
+                input.push('\n');
+                input.push_str(&row.join(" "));
+            }
+        }
+        input
+    }
+}
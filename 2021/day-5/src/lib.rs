@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, split_once, ParseError},
+    solution::Solution,
+};
+
+/// Side of the square the generated vents lie in, as in the puzzle inputs.
+const SIDE: i64 = 1000;
+
+type Line = ((i32, i32), (i32, i32));
+
+/// The points of `line` if it runs straight or at 45 degrees, `None` for any
+/// other slope.
+fn points(&(start, end): &Line) -> Option<impl Iterator<Item = [i64; 2]>> {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+        return None;
+    }
+    let step = (dx.signum(), dy.signum());
+    Some(
+        (0..=dx.abs().max(dy.abs()))
+            .map(move |i| [start.0 + step.0 * i, start.1 + step.1 * i].map(i64::from)),
+    )
+}
+
+/// Number of points covered by at least two of `vents`.
+fn overlaps<'a>(vents: impl Iterator<Item = &'a Line>) -> i32 {
+    let mut covered = HashMap::<[i64; 2], u8>::new();
+    for point in vents.filter_map(points).flatten() {
+        let n = covered.entry(point).or_default();
+        *n = n.saturating_add(1);
+    }
+    covered.values().filter(|n| **n > 1).count() as i32
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Line>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(lines: &str) -> Result<Self::Input, ParseError> {
+        let point = |p| -> Result<(i32, i32), ParseError> {
+            let (x, y) = split_once(lines, p, ",")?;
+            Ok((number(lines, x)?, number(lines, y)?))
+        };
+        lines
+            .lines()
+            .map(|line| {
+                let (start, end) = split_once(lines, line, " -> ")?;
+                Ok((point(start)?, point(end)?))
+            })
+            .collect()
+    }
+
+    fn part1(vents: &Self::Input) -> i32 {
+        overlaps(
+            vents
+                .iter()
+                .filter(|(start, end)| start.0 == end.0 || start.1 == end.1),
+        )
+    }
+
+    fn part2(vents: &Self::Input) -> i32 {
+        overlaps(vents.iter())
+    }
+}
+
+impl Generate for Solver {
+    /// `size` lines of vents, horizontal, vertical or diagonal.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let last = SIDE - 1;
+        (0..size)
+            .map(|_| {
+                let start = (rng.range(0..=last), rng.range(0..=last));
+                let (dx, dy) = *rng.pick(&[(1, 0), (0, 1), (1, 1), (1, -1), (-1, 0), (0, -1)]);
+                let room = |p: i64, d: i64| match d {
+                    1 => last - p,
+                    -1 => p,
+                    _ => last,
+                };
+                let len = rng.range(0..=room(start.0, dx).min(room(start.1, dy)).min(300));
+                let end = (start.0 + dx * len, start.1 + dy * len);
+                format!("{},{} -> {},{}", start.0, start.1, end.0, end.1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
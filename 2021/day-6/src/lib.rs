@@ -0,0 +1,140 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    matrix::{Element, Matrix},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+#[cfg(test)]
+mod reference;
+
+const MAX_TIMER: usize = 9;
+
+fn simulate(fish: &[usize; MAX_TIMER], days: usize) -> usize {
+    let mut timer_table = *fish;
+    for _ in 0..days {
+        next_day(&mut timer_table);
+    }
+    count_fish(&timer_table)
+}
+
+/// One day as a matrix on the number of fish per timer: every timer counts
+/// down, and the fish at 0 go back to 6 and spawn one at 8.
+fn day_matrix<T: Element>() -> Matrix<T> {
+    let count_down = (1..MAX_TIMER).map(|t| (t - 1, t, 1));
+    Matrix::from_entries(MAX_TIMER, count_down.chain([(6, 0, 1), (8, 0, 1)]))
+}
+
+/// The number of fish after `days` days, however many, counted in `T`.
+pub fn fish_after<T: Element>(fish: &[usize; MAX_TIMER], days: u64) -> T {
+    let start = fish.map(|n| T::from_u64(n as u64));
+    let end = day_matrix::<T>().pow(days).apply(&start);
+    end.iter().fold(T::zero(), |sum, n| sum.add(n))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = [usize; MAX_TIMER];
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: &str) -> Result<Self::Input, ParseError> {
+        let mut timer_table = [0_usize; MAX_TIMER];
+        for s in lines.split(',') {
+            let n = number::<usize>(lines, s)?;
+            if n >= MAX_TIMER {
+                return Err(ParseError::at(
+                    lines,
+                    s,
+                    format!("expected a timer below {MAX_TIMER}"),
+                ));
+            }
+            timer_table[n] += 1;
+        }
+        Ok(timer_table)
+    }
+
+    fn part1(fish: &Self::Input) -> usize {
+        simulate(fish, 80)
+    }
+
+    fn part2(fish: &Self::Input) -> usize {
+        simulate(fish, 256)
+    }
+}
+
+fn count_fish(fish: &[usize]) -> usize {
+    let mut sum = 0;
+    for n in fish {
+        sum += *n;
+    }
+    sum
+}
+
+fn next_day(table: &mut [usize]) {
+    let v0 = *table.first().unwrap();
+    for i in 1..table.len() {
+        let d1 = *table.get(i).unwrap();
+        let d2 = table.get_mut(i - 1).unwrap();
+        *d2 = d1;
+    }
+    *table.get_mut(6).unwrap() += v0;
+    *table.get_mut(8).unwrap() = v0;
+}
+
+impl Generate for Solver {
+    /// `size` lanternfish.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| rng.range(1..=5).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::matrix::{BigUint, Mod};
+
+    #[test]
+    fn test_simulate_reference() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let size = rng.below(10) + 1;
+            let input = Solver::generate(&mut rng, size);
+            let timers = input
+                .split(',')
+                .map(|t| t.parse().unwrap())
+                .collect::<Vec<_>>();
+            let fish = Solver::parse(&input).unwrap();
+            for days in (0..=60).step_by(7) {
+                assert_eq!(
+                    simulate(&fish, days),
+                    reference::simulate(&timers, days),
+                    "{input} after {days} days"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fish_after() {
+        let fish = Solver::parse("3,4,3,1,2").unwrap();
+        for days in [0, 1, 18, 80, 256] {
+            assert_eq!(
+                fish_after::<u128>(&fish, days),
+                simulate(&fish, days as usize) as u128
+            );
+        }
+        // far past what fits a u128, the big and the modular counts agree
+        let big = fish_after::<BigUint>(&fish, 10_000);
+        let modular = fish_after::<Mod<1_000_000_007>>(&fish, 10_000);
+        assert_eq!(big % 1_000_000_007u32, BigUint::from(modular.0));
+        assert!(std::panic::catch_unwind(|| fish_after::<u128>(&fish, 10_000)).is_err());
+        // worked out on its own with a plain modular matrix power
+        let modular = fish_after::<Mod<1_000_000_007>>(&fish, 1_000_000_000);
+        assert_eq!(modular.0, 249911329);
+    }
+}
@@ -0,0 +1,19 @@
+//! The lanternfish simulated one by one, to check the timer table against.
+
+/// Fish alive after `days`, starting from the timers of `fish`.
+pub fn simulate(fish: &[usize], days: usize) -> usize {
+    let mut fish = fish.to_vec();
+    for _ in 0..days {
+        let mut born = 0;
+        for timer in fish.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                born += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+        fish.extend(std::iter::repeat_n(8, born));
+    }
+    fish.len()
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+/// Least fuel needed to align every crab on one position, `cost` giving the
+/// fuel a single crab burns to move a distance.
+fn min_fuel(position_map: &HashMap<i32, i32>, cost: impl Fn(i32) -> i32) -> i32 {
+    let max_position = position_map.keys().copied().max().unwrap_or(0);
+    let mut ans_fuel = 0;
+    for i in 0..=max_position {
+        let mut fuel = 0;
+        for (k, v) in position_map {
+            if i != *k {
+                fuel += *v * cost((*k - i).abs());
+            }
+        }
+        if fuel < ans_fuel || ans_fuel == 0 {
+            ans_fuel = fuel;
+        }
+    }
+    ans_fuel
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Number of crabs at each position.
+    type Input = HashMap<i32, i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(line: &str) -> Result<Self::Input, ParseError> {
+        let mut position_map = HashMap::new();
+        for n in line.split(',') {
+            let p = number::<i32>(line, n)?;
+            if let Some(n) = position_map.get_mut(&p) {
+                *n += 1;
+            } else {
+                position_map.insert(p, 1);
+            }
+        }
+        Ok(position_map)
+    }
+
+    fn part1(position_map: &Self::Input) -> i32 {
+        min_fuel(position_map, |distance| distance)
+    }
+
+    fn part2(position_map: &Self::Input) -> i32 {
+        min_fuel(position_map, |distance| distance * (distance + 1) / 2)
+    }
+}
+
+impl Generate for Solver {
+    /// `size` crabs, spread over as many positions (up to 1000).
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let spread = size.clamp(1, 1000) as i64;
+        (0..size.max(1))
+            .map(|_| rng.range(0..=spread).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
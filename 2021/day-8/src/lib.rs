@@ -0,0 +1,254 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{split_once, ParseError},
+    solution::Solution,
+};
+use itertools::Itertools;
+
+/// Work out the wiring from the ten unique patterns of an entry and decode
+/// the digits of its output value.
+fn decode(input: &str, line: &str) -> Result<String, ParseError> {
+    let (patterns, output) = split_once(input, line, "|")?;
+    let digs = [patterns.trim(), output.trim()];
+    if digs[0].split(' ').count() != 10 {
+        return Err(ParseError::at(input, digs[0], "expected ten patterns"));
+    }
+    for v in digs.iter().flat_map(|d| d.split(' ')) {
+        if v.is_empty() || !v.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(ParseError::at(input, v, "expected segments a to g"));
+        }
+    }
+    //
+    let mut zero = String::new();
+    let mut _one = String::new();
+    let mut two = String::new();
+    let mut three = String::new();
+    let mut four = String::new();
+    let mut five = String::new();
+    let mut six = String::new();
+    let mut seven = String::new();
+    let mut _eight = String::new();
+    let mut nine = String::new();
+    // find 1 4 7 8
+    for v in digs.first().unwrap().split(' ') {
+        match v.len() {
+            // digit 1
+            2 => {
+                _one = v.chars().sorted().collect::<String>();
+            }
+            // '4'
+            4 => {
+                //bcdf
+                four = v.chars().sorted().collect::<String>();
+            }
+            // '7'
+            3 => {
+                // acf
+                seven = v.chars().sorted().collect::<String>();
+            }
+            // '8'
+            7 => {
+                //abcdefg
+                _eight = v.chars().sorted().collect::<String>();
+            }
+            _ => (),
+        }
+    }
+    // find other
+    for v in digs.first().unwrap().split(' ') {
+        match v.len() {
+            5 => {
+                // 2/3/5
+                //find 3
+                let mut find = true;
+                for i in seven.as_str().chars() {
+                    let mut temp = false;
+                    for j in v.chars() {
+                        if i == j {
+                            temp = true;
+                        }
+                    }
+                    if !temp {
+                        find = false;
+                        break;
+                    }
+                }
+                if find {
+                    three = v.chars().sorted().collect::<String>();
+                    continue;
+                }
+                // find 2 or 5
+                let mut same = 0;
+                for i in four.as_str().chars() {
+                    for j in v.chars() {
+                        if i == j {
+                            same += 1;
+                        }
+                    }
+                }
+                if same == 2 {
+                    two = v.chars().sorted().collect::<String>();
+                } else if same == 3 {
+                    five = v.chars().sorted().collect::<String>();
+                } else {
+                    return Err(ParseError::at(input, v, "pattern does not fit the wiring"));
+                }
+            }
+            6 => {
+                //find 9
+                let mut find = true;
+                for i in four.as_str().chars() {
+                    let mut temp = false;
+                    for j in v.chars() {
+                        if i == j {
+                            temp = true;
+                        }
+                    }
+                    if !temp {
+                        find = false;
+                        break;
+                    }
+                }
+                if find {
+                    nine = v.chars().sorted().collect::<String>();
+                    continue;
+                }
+                // find 0 or 6
+                let mut find = true;
+                for i in seven.as_str().chars() {
+                    let mut temp = false;
+                    for j in v.chars() {
+                        if i == j {
+                            temp = true;
+                        }
+                    }
+                    if !temp {
+                        find = false;
+                        break;
+                    }
+                }
+                if find {
+                    zero = v.chars().sorted().collect::<String>();
+                    continue;
+                } else {
+                    six = v.chars().sorted().collect::<String>();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    //
+    let mut s = "".to_string();
+    for v in digs.get(1).unwrap().split(' ') {
+        match v.len() {
+            // digit 1
+            2 => {
+                s.push('1');
+            }
+            // '4'
+            4 => {
+                s.push('4');
+            }
+            // '7'
+            3 => {
+                s.push('7');
+            }
+            // '8'
+            7 => {
+                s.push('8');
+            }
+            // 0/6/9
+            6 => {
+                let t = v.chars().sorted().collect::<String>();
+                if t.as_str() == zero.as_str() {
+                    s.push('0');
+                } else if t.as_str() == six.as_str() {
+                    s.push('6');
+                } else if t.as_str() == nine.as_str() {
+                    s.push('9');
+                } else {
+                    return Err(ParseError::at(input, v, "pattern does not fit the wiring"));
+                }
+            }
+            5 => {
+                let t = v.chars().sorted().collect::<String>();
+                if t.as_str() == two.as_str() {
+                    s.push('2');
+                } else if t.as_str() == three.as_str() {
+                    s.push('3');
+                } else if t.as_str() == five.as_str() {
+                    s.push('5');
+                } else {
+                    return Err(ParseError::at(input, v, "pattern does not fit the wiring"));
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(s)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        s.lines().map(|line| decode(s, line)).collect()
+    }
+
+    fn part1(outputs: &Self::Input) -> usize {
+        // the digits using a unique number of segments
+        outputs
+            .iter()
+            .flat_map(|s| s.chars())
+            .filter(|c| matches!(c, '1' | '4' | '7' | '8'))
+            .count()
+    }
+
+    fn part2(outputs: &Self::Input) -> i32 {
+        outputs.iter().map(|s| s.parse::<i32>().unwrap()).sum()
+    }
+}
+
+impl Generate for Solver {
+    /// `size` entries, each with its own wiring.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const DIGITS: [&str; 10] = [
+            "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+        ];
+        let pattern = |rng: &mut Rng, wires: &[char], digit: usize| {
+            let mut segments = DIGITS[digit]
+                .bytes()
+                .map(|b| wires[(b - b'a') as usize])
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut segments);
+            segments.into_iter().collect::<String>()
+        };
+        (0..size)
+            .map(|_| {
+                let mut wires = "abcdefg".chars().collect::<Vec<_>>();
+                rng.shuffle(&mut wires);
+                let mut digits = (0..10).collect::<Vec<_>>();
+                rng.shuffle(&mut digits);
+                let patterns = digits
+                    .iter()
+                    .map(|&d| pattern(rng, &wires, d))
+                    .collect::<Vec<_>>();
+                let output = (0..4)
+                    .map(|_| {
+                        let digit = rng.below(10);
+                        pattern(rng, &wires, digit)
+                    })
+                    .collect::<Vec<_>>();
+                format!("{} | {}", patterns.join(" "), output.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
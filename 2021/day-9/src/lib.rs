@@ -0,0 +1,182 @@
+use std::cmp::Ord;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    grid::{Grid, Pos},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Debug, Eq, Clone)]
+pub struct Cell {
+    n: i32,
+    low: bool,
+    visited: bool,
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.n.cmp(&other.n)
+    }
+}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Cell {
+    fn new(n: i32) -> Self {
+        Self {
+            n,
+            low: false,
+            visited: false,
+        }
+    }
+    fn is_low(&self) -> bool {
+        self.low
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_low() {
+            write!(f, "[{}]", self.n)
+        } else {
+            write!(f, "{}", self.n)
+        }
+    }
+}
+
+pub type HeightMap = Grid<Cell>;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = HeightMap;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut map = Grid::parse(input, |c| c.to_digit(10).map(|n| Cell::new(n as i32)))?;
+        low_point(&mut map);
+        if map.cells().iter().filter(|cell| cell.is_low()).count() < 3 {
+            return Err(ParseError::end(input, "expected at least three low points"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> i32 {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> i32 {
+        part2(&mut map.clone())
+    }
+}
+
+fn dfs(pos: Pos, map: &mut HeightMap) -> i32 {
+    let cell = &mut map[pos];
+    if cell.visited || cell.n == 9 {
+        return 0;
+    }
+    cell.visited = true;
+
+    let neighbors = map.neighbors4(pos).collect::<Vec<_>>();
+    1 + neighbors.into_iter().map(|n| dfs(n, map)).sum::<i32>()
+}
+
+fn part2(map: &mut HeightMap) -> i32 {
+    let mut list = vec![];
+    for pos in map.positions() {
+        if map[pos].is_low() {
+            let cnt = dfs(pos, map);
+            list.push(cnt);
+        }
+    }
+    list.sort_unstable();
+    list.reverse();
+    let mut multi = 1;
+    list.get(0..3).unwrap().iter().for_each(|n| multi *= n);
+    multi
+}
+
+fn part1(map: &HeightMap) -> i32 {
+    let mut ans = 0;
+    for cell in map.cells() {
+        if cell.is_low() {
+            ans += cell.n + 1;
+        }
+    }
+    ans
+}
+
+fn low_point(map: &mut HeightMap) {
+    for pos in map.positions() {
+        let cell = &map[pos];
+        if map.neighbors4(pos).all(|n| map[n] > *cell) {
+            map[pos].low = true;
+        }
+    }
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` map (at least 5) of basins around scattered low
+    /// points, about one per 50 locations, walled off by 9s.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(5);
+        let mut spots = (0..side * side)
+            .map(|i| (i / side, i % side))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut spots);
+        let distance = |a: (usize, usize), b: (usize, usize)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+        let mut lows: Vec<(usize, usize)> = vec![];
+        for spot in spots {
+            if lows.len() < (side * side / 50).max(3)
+                && lows.iter().all(|&l| distance(l, spot) >= 3)
+            {
+                lows.push(spot);
+            }
+        }
+        (0..side)
+            .map(|row| {
+                (0..side)
+                    .map(|col| {
+                        let distances = lows.iter().map(|&l| distance(l, (row, col)));
+                        let nearest = distances.clone().min().unwrap();
+                        // a ridge where two basins meet
+                        let height = if distances.filter(|&d| d == nearest).count() > 1 {
+                            9
+                        } else {
+                            nearest.min(8)
+                        };
+                        char::from_digit(height as u32, 10).unwrap()
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_cmp() {
+        let cell1 = Cell::new(1);
+        let cell2 = Cell::new(2);
+        let cell3 = Cell::new(3);
+
+        assert!(cell1 == cell1);
+        assert!(cell1 < cell2);
+        assert!(cell3 > cell2);
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, ParseError},
+    solution::Solution,
+};
+
+fn calories_elf_carray(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|calories| {
+            calories
+                .lines()
+                .map(|calory| number::<i32>(input, calory))
+                .sum()
+        })
+        .collect()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Calories carried by each elf.
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        calories_elf_carray(input)
+    }
+
+    fn part1(calories: &Self::Input) -> i32 {
+        *calories.iter().max().unwrap()
+    }
+
+    fn part2(calories: &Self::Input) -> i32 {
+        let mut calories = calories.clone();
+        calories.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        calories.iter().take(3).sum()
+    }
+}
+
+impl Generate for Solver {
+    /// The snacks of `size` elves (at least 3).
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(3))
+            .map(|_| {
+                (0..rng.range(1..=10))
+                    .map(|_| rng.range(1000..=60_000).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    static SAMPLE: &str = include_str!("sample");
+    use super::*;
+    #[test]
+    fn test_part1_sample() {
+        let max = Solver::part1(&Solver::parse(SAMPLE).unwrap());
+        assert_eq!(max, 24000);
+    }
+    #[test]
+    fn test_part2_sample() {
+        let max = Solver::part2(&Solver::parse(SAMPLE).unwrap());
+        assert_eq!(max, 45000);
+    }
+}
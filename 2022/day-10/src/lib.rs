@@ -0,0 +1,192 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    ocr,
+    parse::{next, number, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmd {
+    Addx,
+    Noop,
+}
+
+impl Cmd {
+    fn cycle(&self) -> i32 {
+        match *self {
+            Self::Addx => 2,
+            Self::Noop => 1,
+        }
+    }
+}
+
+impl Cmd {
+    fn parse(input: &str, cmd: &str) -> Result<Self, ParseError> {
+        match cmd {
+            "addx" => Ok(Self::Addx),
+            "noop" => Ok(Self::Noop),
+            _ => Err(ParseError::at(input, cmd, "expected addx or noop")),
+        }
+    }
+}
+
+/// An instruction with its operand, 0 for `noop`.
+type Instruction = (Cmd, i32);
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let mut cmd_line = line.split(' ');
+            let cmd = Cmd::parse(input, next(input, line, &mut cmd_line, "an instruction")?)?;
+            let mut value = 0;
+            if cmd == Cmd::Addx {
+                value = number(input, next(input, line, &mut cmd_line, "a number")?)?;
+            }
+            if let Some(extra) = cmd_line.next() {
+                return Err(ParseError::at(input, extra, "expected the end of the line"));
+            }
+            Ok((cmd, value))
+        })
+        .collect()
+}
+struct Cpu {
+    x: i32,
+    cycle: i32,
+    strengths: i32,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self {
+            x: 1,
+            cycle: 0,
+            strengths: 0,
+        }
+    }
+
+    fn signal_cycle(cycle: i32) -> bool {
+        cycle == 20 || cycle == 60 || cycle == 100 || cycle == 140 || cycle == 180 || cycle == 220
+    }
+
+    fn exec_cmd(&mut self, cmd: Cmd, value: i32, crt: &mut Crt) {
+        let cycle = cmd.cycle();
+        for _ in 0..cycle {
+            self.cycle += 1;
+            crt.draw(self);
+            if Self::signal_cycle(self.cycle) {
+                self.strengths += self.cycle * self.x;
+            }
+        }
+        self.x += value;
+    }
+
+    fn exec_program(&mut self, program: &[Instruction], crt: &mut Crt) {
+        program
+            .iter()
+            .for_each(|&(cmd, value)| self.exec_cmd(cmd, value, crt));
+    }
+}
+
+#[derive(Debug)]
+struct Crt {
+    screen: [[u8; Crt::CRT_WIDTH]; Crt::CRT_HIGHT],
+    x: usize,
+    y: usize,
+}
+
+impl Crt {
+    const CRT_WIDTH: usize = 40;
+    const CRT_HIGHT: usize = 6;
+    fn new() -> Self {
+        Self {
+            screen: [[0; Crt::CRT_WIDTH]; Crt::CRT_HIGHT],
+            x: 0,
+            y: 0,
+        }
+    }
+    fn draw(&mut self, cpu: &Cpu) {
+        if self.x >= Crt::CRT_HIGHT {
+            return;
+        }
+
+        if self.y as i32 + 1 >= cpu.x && self.y as i32 + 1 < cpu.x + 3 {
+            self.screen[self.x][self.y] = 1;
+        }
+        self.y += 1;
+        if self.y >= Crt::CRT_WIDTH {
+            self.y = 0;
+            self.x += 1;
+        }
+    }
+}
+
+impl std::fmt::Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.screen.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for pixel in row {
+                write!(f, "{}", if *pixel > 0 { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(program: &Self::Input) -> i32 {
+        let mut cpu = Cpu::new();
+        cpu.exec_program(program, &mut Crt::new());
+        cpu.strengths
+    }
+
+    fn part2(program: &Self::Input) -> String {
+        let mut crt = Crt::new();
+        Cpu::new().exec_program(program, &mut crt);
+        ocr::read_art_or_keep(crt.to_string())
+    }
+}
+
+impl Generate for Solver {
+    /// A program of `size` instructions, keeping the sprite on the screen.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut x = 1;
+        (0..size)
+            .map(|_| {
+                if rng.chance(0.3) {
+                    return "noop".to_string();
+                }
+                let to = (x + rng.range(-5..=5)).clamp(0, 39);
+                let add = to - x;
+                x = to;
+                format!("addx {add}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let mut cpu = Cpu::new();
+        let mut crt = Crt::new();
+        cpu.exec_program(&parse_program(SAMPLE).unwrap(), &mut crt);
+        assert_eq!(13140, cpu.strengths);
+    }
+}
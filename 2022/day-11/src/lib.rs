@@ -0,0 +1,438 @@
+use std::cell::RefCell;
+
+use aoc_common::{
+    cycle,
+    generate::{Generate, Rng},
+    parse::{number, split_once, strip_prefix, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Default, Clone, Copy)]
+enum Operator {
+    #[default]
+    Nop,
+    Add,
+    Mul,
+    Mod,
+    Square,
+}
+
+impl Operator {
+    fn parse(input: &str, op: &str) -> Result<Self, ParseError> {
+        match op {
+            "+" => Ok(Self::Add),
+            //"-" => Self::Sub,
+            "*" => Ok(Self::Mul),
+            //"/" => Self::Div,
+            _ => Err(ParseError::at(input, op, "expected + or *")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Operation {
+    operator: Operator,
+    value: u64,
+}
+
+impl Operation {
+    fn new(operator: Operator, value: u64) -> Self {
+        Self { operator, value }
+    }
+
+    fn eval(&self, value: &u64) -> u64 {
+        match self.operator {
+            Operator::Nop => *value,
+            Operator::Add => value + self.value,
+            Operator::Mul => value * self.value,
+            Operator::Mod => value % self.value,
+            Operator::Square => value * value,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    op: Operation,
+    test: Operation,
+    throw: (u32, u32),
+    inspects_items_count: u64,
+}
+
+impl Monkey {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn round<T>(&mut self, limit: T) -> Vec<(u32, u64)>
+    where
+        T: Fn(u64) -> u64,
+    {
+        let mut throw_result: Vec<(u32, u64)> = vec![];
+        for item in self.items.iter() {
+            self.inspects_items_count += 1;
+            let mut op_result = self.op.eval(item);
+            op_result = limit(op_result);
+            if self.test.eval(&op_result) == 0 {
+                // (monkey index, value)
+                throw_result.push((self.throw.0, op_result));
+            } else {
+                throw_result.push((self.throw.1, op_result));
+            }
+        }
+        self.items.clear();
+        throw_result
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkeys(Vec<RefCell<Monkey>>);
+
+impl Monkeys {
+    fn new() -> Self {
+        Self(vec![])
+    }
+
+    fn push(&mut self, monkey: Monkey) {
+        self.0.push(RefCell::new(monkey));
+    }
+
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut monkeys = Self::new();
+        // where each throw target was given, checked once every monkey is known
+        let mut throw_targets = vec![];
+        for line in input.lines() {
+            if line.starts_with("Monkey") {
+                monkeys.push(Monkey::new());
+                continue;
+            } else if line.is_empty() {
+                continue;
+            }
+            let Some(monkey) = monkeys.0.last_mut() else {
+                return Err(ParseError::at(input, line, "expected a Monkey header"));
+            };
+            let monkey = monkey.get_mut();
+            if let Some(items) = line.strip_prefix("  Starting items: ") {
+                monkey.items = items
+                    .split(", ")
+                    .map(|item| number(input, item))
+                    .collect::<Result<_, _>>()?;
+            } else if let Some(operation) = line.strip_prefix("  Operation: new = ") {
+                let operation = strip_prefix(input, operation, "old ")?;
+                let (operator, op_value) = split_once(input, operation, " ")?;
+                let operator = Operator::parse(input, operator)?;
+                monkey.op = match (operator, op_value) {
+                    (Operator::Mul, "old") => Operation::new(Operator::Square, 0),
+                    (Operator::Add, "old") => Operation::new(Operator::Mul, 2),
+                    _ => Operation::new(operator, number(input, op_value)?),
+                };
+            } else if let Some(test) = line.strip_prefix("  Test: ") {
+                let test = strip_prefix(input, test, "divisible by ")?;
+                let divisor = number(input, test)?;
+                if divisor == 0 {
+                    return Err(ParseError::at(input, test, "expected a non-zero divisor"));
+                }
+                monkey.test = Operation::new(Operator::Mod, divisor);
+            } else {
+                let (branch, throw_to) = split_once(input, line, " throw to monkey ")?;
+                throw_targets.push(throw_to);
+                let throw_to = number(input, throw_to)?;
+                match branch {
+                    "    If true:" => monkey.throw.0 = throw_to,
+                    "    If false:" => monkey.throw.1 = throw_to,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            branch,
+                            "expected If true or If false",
+                        ))
+                    }
+                }
+            }
+        }
+        // monkey business is the two busiest monkeys at it
+        if monkeys.0.len() < 2 {
+            return Err(ParseError::end(input, "expected at least two monkeys"));
+        }
+        for target in throw_targets {
+            if number::<usize>(input, target)? >= monkeys.0.len() {
+                return Err(ParseError::at(input, target, "no monkey with that number"));
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn throw(&self, throw: (u32, u64)) {
+        self.0
+            .get(throw.0 as usize)
+            .unwrap()
+            .borrow_mut()
+            .items
+            .push(throw.1);
+    }
+
+    fn round<T>(&self, limit: T)
+    where
+        T: Fn(u64) -> u64,
+    {
+        for monkey in self.0.iter() {
+            let throw_result = monkey.borrow_mut().round(&limit);
+            for throw in throw_result {
+                self.throw(throw);
+            }
+        }
+    }
+
+    fn monkey_business(&self, active: u32) -> u64 {
+        let inspected = self
+            .0
+            .iter()
+            .map(|monkey| monkey.borrow().inspects_items_count);
+        business(inspected.collect(), active) as u64
+    }
+
+    fn part1(&self) {
+        for _ in 0..20 {
+            self.round(|x: u64| x / 3);
+        }
+    }
+
+    /// The product of every divisor tested, which worry levels can be kept
+    /// below without changing where any item goes.
+    fn limit(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|monkey| monkey.borrow().test.value)
+            .product()
+    }
+
+    fn part2(&self) {
+        let limit = self.limit();
+        for _ in 0..10000 {
+            self.round(|x: u64| x % limit);
+        }
+    }
+
+    /// Where an item held by `monkey` at `worry` is at the end of the round,
+    /// worry levels kept below `limit`, counting the inspections on its way
+    /// into `inspected`.
+    fn item_round(
+        &self,
+        (mut monkey, mut worry): (usize, u64),
+        limit: u64,
+        inspected: &mut [u64],
+    ) -> (usize, u64) {
+        loop {
+            let m = self.0[monkey].borrow();
+            inspected[monkey] += 1;
+            worry = m.op.eval(&worry) % limit;
+            let to = if m.test.eval(&worry) == 0 {
+                m.throw.0
+            } else {
+                m.throw.1
+            } as usize;
+            // a monkey that had its turn already keeps it for the next round
+            let waits = to <= monkey;
+            monkey = to;
+            if waits {
+                return (monkey, worry);
+            }
+        }
+    }
+
+    /// Monkey business after `rounds` rounds of the second part, however
+    /// many. An item goes the same way whatever the others do, so each one
+    /// is fast-forwarded round its own cycle.
+    pub fn business_after(&self, rounds: u64) -> u128 {
+        let count = self.0.len();
+        let limit = self.limit();
+        let mut inspected = vec![0; count];
+        // what the cycle search inspects is counted again once it is known
+        let mut ignored = vec![0; count];
+        for (i, monkey) in self.0.iter().enumerate() {
+            for &worry in &monkey.borrow().items {
+                let start = (i, worry);
+                let step = |item: &(usize, u64)| self.item_round(*item, limit, &mut ignored);
+                let cycle = cycle::find(&start, step, rounds);
+                let steps = cycle.map_or(rounds, |c| (c.offset + c.period) as u64);
+                // totals[m][k]: inspections by monkey m in the first k rounds
+                let mut totals = vec![vec![0]; count];
+                let (mut item, mut counts) = (start, vec![0; count]);
+                for _ in 0..steps {
+                    item = self.item_round(item, limit, &mut counts);
+                    totals.iter_mut().zip(&counts).for_each(|(t, c)| t.push(*c));
+                }
+                for (n, totals) in inspected.iter_mut().zip(&totals) {
+                    *n += match cycle {
+                        Some(cycle) => cycle.total(totals, rounds),
+                        None => totals[rounds as usize],
+                    };
+                }
+            }
+        }
+        business(inspected, 2)
+    }
+}
+
+/// The product of the `active` highest inspection counts.
+fn business(mut inspected: Vec<u64>, active: u32) -> u128 {
+    inspected.sort_by(|a, b| b.cmp(a));
+    inspected[..active as usize]
+        .iter()
+        .map(|&n| u128::from(n))
+        .product()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Monkeys;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Monkeys::parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> u64 {
+        let monkeys = monkeys.clone();
+        monkeys.part1();
+        monkeys.monkey_business(2)
+    }
+
+    fn part2(monkeys: &Self::Input) -> u64 {
+        let monkeys = monkeys.clone();
+        monkeys.part2();
+        monkeys.monkey_business(2)
+    }
+}
+
+/// A monkey made up for a note.
+struct Note {
+    items: Vec<u64>,
+    /// `None` squares the worry level.
+    op: Option<(char, u64)>,
+    divisor: u64,
+    throw: (usize, usize),
+}
+
+impl Note {
+    fn inspect(&self, old: u64) -> Option<u64> {
+        match self.op {
+            Some(('+', n)) => old.checked_add(n),
+            Some((_, n)) => old.checked_mul(n),
+            None => old.checked_mul(old),
+        }
+    }
+}
+
+/// Whether the worry levels of `notes` fit for the 20 rounds of the first
+/// part, where nothing keeps them small but the division by 3.
+fn calm(notes: &[Note]) -> bool {
+    let mut items = notes.iter().map(|n| n.items.clone()).collect::<Vec<_>>();
+    for _ in 0..20 {
+        for (i, note) in notes.iter().enumerate() {
+            for old in std::mem::take(&mut items[i]) {
+                let Some(new) = note.inspect(old) else {
+                    return false;
+                };
+                let new = new / 3;
+                let to = if new.is_multiple_of(note.divisor) {
+                    note.throw.0
+                } else {
+                    note.throw.1
+                };
+                items[to].push(new);
+            }
+        }
+    }
+    true
+}
+
+impl Generate for Solver {
+    /// Notes on `size` monkeys (2 to 8, testing distinct primes), made up
+    /// until the worry levels of the first part fit.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, 8);
+        let notes = loop {
+            let mut primes = [2, 3, 5, 7, 11, 13, 17, 19];
+            rng.shuffle(&mut primes);
+            let notes = (0..count)
+                .map(|i| {
+                    let others = (0..count).filter(|&m| m != i).collect::<Vec<_>>();
+                    let if_true = *rng.pick(&others);
+                    let if_false = *rng.pick(&others);
+                    Note {
+                        items: (0..rng.range(1..=8))
+                            .map(|_| rng.range(50..=99) as u64)
+                            .collect(),
+                        op: match rng.below(8) {
+                            0 => None,
+                            1 | 2 => Some(('*', rng.range(2..=19) as u64)),
+                            _ => Some(('+', rng.range(1..=8) as u64)),
+                        },
+                        divisor: primes[i],
+                        throw: (if_true, if_false),
+                    }
+                })
+                .collect::<Vec<_>>();
+            if calm(&notes) {
+                break notes;
+            }
+        };
+        notes
+            .iter()
+            .enumerate()
+            .map(|(i, note)| {
+                let items = note.items.iter().map(u64::to_string).collect::<Vec<_>>();
+                let op = match note.op {
+                    Some((op, n)) => format!("{op} {n}"),
+                    None => "* old".to_string(),
+                };
+                format!(
+                    "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {op}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}",
+                    items.join(", "),
+                    note.divisor,
+                    note.throw.0,
+                    note.throw.1
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let monkeys = Monkeys::parse_input(SAMPLE).unwrap();
+        monkeys.part1();
+        assert_eq!(10605, monkeys.monkey_business(2));
+    }
+    #[test]
+    fn test_part2_sample() {
+        let monkeys = Monkeys::parse_input(SAMPLE).unwrap();
+        monkeys.part2();
+        assert_eq!(2713310158, monkeys.monkey_business(2));
+    }
+    #[test]
+    fn test_business_after() {
+        let monkeys = Monkeys::parse_input(SAMPLE).unwrap();
+        assert_eq!(monkeys.business_after(10000), 2713310158);
+        for rounds in [1, 20, 1000] {
+            let direct = monkeys.clone();
+            let limit = direct.limit();
+            (0..rounds).for_each(|_| direct.round(|x: u64| x % limit));
+            let business = u128::from(direct.monkey_business(2));
+            assert_eq!(monkeys.business_after(rounds), business);
+        }
+    }
+}
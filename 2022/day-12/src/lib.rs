@@ -0,0 +1,165 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    grid::{Cell, Grid, Pos},
+    parse::ParseError,
+    search::{bfs, Path},
+    solution::Solution,
+};
+
+#[derive(Cell, Clone, Copy, PartialEq, Eq)]
+enum Square {
+    #[cell('S')]
+    Start,
+    #[cell('E')]
+    End,
+    #[cell('a'..='z')]
+    Elevation(char),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HeightMap {
+    elevations: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+impl HeightMap {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let squares = Grid::<Square>::parse_cells(input)?;
+        let find = |mark| -> Result<Pos, ParseError> {
+            squares
+                .position(|square| *square == mark)
+                .ok_or_else(|| ParseError::end(input, format!("expected a square marked {mark}")))
+        };
+        let start = find(Square::Start)?;
+        let end = find(Square::End)?;
+        let elevations = squares.map(|square| match square {
+            Square::Start => 'a',
+            Square::End => 'z',
+            Square::Elevation(e) => *e,
+        });
+        let map = Self {
+            elevations,
+            start,
+            end,
+        };
+        // S is at elevation a, so every a reaches E once S does
+        if map.shortest_path([start]).is_none() {
+            let e = input.find('E').unwrap();
+            return Err(ParseError::at(
+                input,
+                &input[e..e + 1],
+                "expected a path climbing from S to E",
+            ));
+        }
+        Ok(map)
+    }
+
+    fn can_climb(&self, from: Pos, to: Pos) -> bool {
+        self.elevations[to] as i32 - self.elevations[from] as i32 <= 1
+    }
+
+    fn shortest_path(&self, starts: impl IntoIterator<Item = Pos>) -> Option<Path<Pos, usize>> {
+        bfs(
+            starts,
+            |p| {
+                let p = *p;
+                self.elevations
+                    .neighbors4(p)
+                    .filter(move |n| self.can_climb(p, *n))
+            },
+            |p| *p == self.end,
+        )
+    }
+
+    fn fewest_path(&self, start: Pos) -> usize {
+        self.shortest_path([start])
+            .expect("parse checks that S reaches E")
+            .cost
+    }
+
+    fn fewest_path2(&self) -> usize {
+        let a_start = self
+            .elevations
+            .iter()
+            .filter(|(_, e)| **e == 'a')
+            .map(|(p, _)| p);
+        self.shortest_path(a_start)
+            .expect("parse checks that S reaches E")
+            .cost
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        HeightMap::parse_input(input)
+    }
+
+    fn part1(hight_map: &Self::Input) -> usize {
+        hight_map.fewest_path(hight_map.start)
+    }
+
+    fn part2(hight_map: &Self::Input) -> usize {
+        hight_map.fewest_path2()
+    }
+}
+
+impl Generate for Solver {
+    /// A map `size` squares wide (at least 26) and half as high. Elevations
+    /// rise across it, never too steeply along the row from S to E.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = size.max(26);
+        let height = (width / 2).max(1);
+        let path = rng.below(height);
+        let mut map = (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| {
+                        let slope = col * 26 / width;
+                        let dip = if row == path { 0 } else { rng.below(3) };
+                        (b'a' + slope.saturating_sub(dip) as u8) as char
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        map[path][0] = 'S';
+        map[path][width - 1] = 'E';
+        map.iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let hight_map = HeightMap::parse_input(SAMPLE).unwrap();
+        assert_eq!(hight_map.fewest_path(hight_map.start), 31);
+        let path = hight_map.shortest_path([hight_map.start]).unwrap();
+        assert_eq!(path.nodes.len(), 32);
+        assert_eq!(path.nodes.last(), Some(&hight_map.end));
+    }
+    #[test]
+    fn test_part2_sample() {
+        let hight_map = HeightMap::parse_input(SAMPLE).unwrap();
+        assert_eq!(hight_map.fewest_path2(), 29);
+    }
+    #[test]
+    fn test_unreachable_end() {
+        let e = Solver::parse("SzE").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+        assert_eq!(e.message, "expected a path climbing from S to E");
+    }
+}
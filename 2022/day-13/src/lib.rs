@@ -0,0 +1,196 @@
+use std::cmp::Ordering;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::ParseError,
+    solution::Solution,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum Packet {
+    Number(i32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn parse_input(input: &str) -> Result<Vec<Self>, ParseError> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| {
+                    // serde_json counts columns in bytes from 1
+                    let i = (e.column().saturating_sub(1)..=line.len())
+                        .find(|i| line.is_char_boundary(*i))
+                        .unwrap();
+                    let token = line[i..]
+                        .chars()
+                        .next()
+                        .map_or("", |c| &line[i..i + c.len_utf8()]);
+                    ParseError::at(input, token, "expected a list of numbers and lists")
+                })
+            })
+            .collect()
+    }
+
+    fn list_map<F, T>(&self, map: F) -> T
+    where
+        F: Fn(&[Packet]) -> T,
+    {
+        match self {
+            Self::Number(n) => map(&[Self::Number(*n)]),
+            Self::List(l) => map(&l[..]),
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::*;
+        match (self, other) {
+            (Number(n1), Number(n2)) => n1.cmp(n2),
+            (left, right) => left.list_map(|l| right.list_map(|r| l.cmp(r))),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Packets<'a> {
+    packets: &'a Vec<Packet>,
+    step: usize,
+    start: usize,
+}
+
+impl<'a> Packets<'a> {
+    fn new(packets: &'a Vec<Packet>) -> Self {
+        Self {
+            packets,
+            // iterator every 2 packet
+            step: 2,
+            start: 0,
+        }
+    }
+    fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    fn part1(&self) -> usize {
+        self.enumerate()
+            .filter_map(|(index, (p1, p2))| if p1 < p2 { Some(index + 1) } else { None })
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        use Packet::*;
+        let mut packets = self.packets.clone();
+        let div = vec![
+            List(vec![List(vec![Number(2)])]),
+            List(vec![List(vec![Number(6)])]),
+        ];
+        packets.append(&mut div.clone());
+        packets.sort();
+        let mut key = 1;
+        div.iter().for_each(|p| {
+            key *= packets.binary_search(p).unwrap() + 1;
+        });
+        key
+    }
+}
+
+impl<'a> Iterator for Packets<'a> {
+    type Item = (&'a Packet, &'a Packet);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() < self.step || self.start + self.step > self.len() {
+            return None;
+        }
+        let pair = Some((
+            self.packets.get(self.start).unwrap(),
+            self.packets.get(self.start + 1).unwrap(),
+        ));
+
+        self.start += self.step;
+
+        pair
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let packets = Packet::parse_input(input)?;
+        if packets.len() % 2 != 0 {
+            return Err(ParseError::end(input, "expected packets in pairs"));
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Self::Input) -> usize {
+        Packets::new(packets).part1()
+    }
+
+    fn part2(packets: &Self::Input) -> usize {
+        Packets::new(packets).part2()
+    }
+}
+
+/// A packet list nested at most `depth` more lists deep.
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.range(0..=5))
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                packet(rng, depth - 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+impl Generate for Solver {
+    /// `size` pairs of packets.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n{}", packet(rng, 4), packet(rng, 4)))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let packets = Packet::parse_input(SAMPLE).unwrap();
+        let packets = Packets::new(&packets);
+        assert_eq!(packets.len() % 2, 0);
+
+        let sum = packets.part1();
+        assert_eq!(sum, 13);
+    }
+
+    #[test]
+    fn test_part2_sample() {
+        let packets = Packet::parse_input(SAMPLE).unwrap();
+        let packets = Packets::new(&packets);
+        assert_eq!(packets.len() % 2, 0);
+
+        let key = packets.part2();
+        assert_eq!(key, 140)
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{split_once, ParseError},
+    solution::Solution,
+};
+
+#[derive(Clone, Copy)]
+enum RoundStatus {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl RoundStatus {
+    fn score(self) -> i32 {
+        match self {
+            Self::Win => 6,
+            Self::Loss => 0,
+            Self::Draw => 3,
+        }
+    }
+}
+#[derive(Clone, Copy)]
+enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Rps {
+    fn score(self) -> i32 {
+        match self {
+            Rps::Rock => 1,
+            Rps::Paper => 2,
+            Rps::Scissors => 3,
+        }
+    }
+
+    fn win_lose_draw(self, opp: Rps) -> RoundStatus {
+        match (self, opp) {
+            (Rps::Rock, Rps::Scissors) | (Rps::Paper, Rps::Rock) | (Rps::Scissors, Rps::Paper) => {
+                RoundStatus::Win
+            }
+            (Rps::Rock, Rps::Paper) | (Rps::Paper, Rps::Scissors) | (Rps::Scissors, Rps::Rock) => {
+                RoundStatus::Loss
+            }
+            (Rps::Rock, Rps::Rock) | (Rps::Paper, Rps::Paper) | (Rps::Scissors, Rps::Scissors) => {
+                RoundStatus::Draw
+            }
+        }
+    }
+
+    fn trans_to_status(self) -> RoundStatus {
+        match self {
+            Rps::Rock => RoundStatus::Loss,
+            Rps::Paper => RoundStatus::Draw,
+            Rps::Scissors => RoundStatus::Win,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Rps::Rock => Rps::Paper,
+            Rps::Paper => Rps::Scissors,
+            Rps::Scissors => Rps::Rock,
+        }
+    }
+}
+
+impl Rps {
+    fn parse(input: &str, c: &str) -> Result<Self, ParseError> {
+        match c {
+            "A" | "X" => Ok(Rps::Rock),
+            "B" | "Y" => Ok(Rps::Paper),
+            "C" | "Z" => Ok(Rps::Scissors),
+            _ => Err(ParseError::at(input, c, "expected A, B, C, X, Y or Z")),
+        }
+    }
+}
+
+struct Round {
+    opponent: Rps,
+    me: Rps,
+}
+
+impl Round {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (opp, me) = split_once(input, line, " ")?;
+        Ok(Self {
+            opponent: Rps::parse(input, opp)?,
+            me: Rps::parse(input, me)?,
+        })
+    }
+
+    fn score_part1(&self) -> i32 {
+        self.me.score() + self.me.win_lose_draw(self.opponent).score()
+    }
+
+    fn score_part2(&self) -> i32 {
+        let status = self.me.trans_to_status();
+        let rps = match status {
+            RoundStatus::Win => self.opponent.next(),
+            RoundStatus::Loss => self.opponent.next().next(),
+            RoundStatus::Draw => self.opponent,
+        };
+        rps.score() + status.score()
+    }
+}
+
+pub struct Guide(Vec<Round>);
+
+impl Guide {
+    fn new(guide: Vec<Round>) -> Self {
+        Self(guide)
+    }
+
+    fn score_part1(&self) -> i32 {
+        self.0.iter().map(|round| round.score_part1()).sum()
+    }
+
+    fn score_part2(&self) -> i32 {
+        self.0.iter().map(|round| round.score_part2()).sum()
+    }
+}
+
+fn parse_strategy_guide(input: &str) -> Result<Vec<Round>, ParseError> {
+    input
+        .lines()
+        .map(|line| Round::parse(input, line))
+        .collect()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Guide;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Guide::new(parse_strategy_guide(input)?))
+    }
+
+    fn part1(guide: &Self::Input) -> i32 {
+        guide.score_part1()
+    }
+
+    fn part2(guide: &Self::Input) -> i32 {
+        guide.score_part2()
+    }
+}
+
+impl Generate for Solver {
+    /// A strategy guide of `size` rounds.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.pick(&["A", "B", "C"]),
+                    rng.pick(&["X", "Y", "Z"])
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let guide = Guide::new(parse_strategy_guide(SAMPLE).unwrap());
+        assert_eq!(guide.score_part1(), 15);
+    }
+    #[test]
+    fn test_part2_sample() {
+        let guide = Guide::new(parse_strategy_guide(SAMPLE).unwrap());
+        assert_eq!(guide.score_part2(), 12);
+    }
+}
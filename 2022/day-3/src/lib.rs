@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{invalid_char, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+struct Rucksack<'a> {
+    first: &'a str,
+    second: &'a str,
+}
+
+impl<'a> Rucksack<'a> {
+    fn find_common_item_type(&self) -> HashSet<char> {
+        self.first
+            .chars()
+            .filter(|c| self.second.find(*c).is_some())
+            .collect()
+    }
+
+    fn parse_input(input: &str) -> Vec<Rucksack<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                let line = line.split_at(line.len() / 2);
+                Rucksack {
+                    first: line.0,
+                    second: line.1,
+                }
+            })
+            .collect()
+    }
+
+    fn sum_priority(rucksacks: &[Self]) -> i32 {
+        rucksacks
+            .iter()
+            .map(|r| {
+                r.find_common_item_type()
+                    .iter()
+                    .map(|c| priority(*c))
+                    .sum::<i32>()
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ElfGroup<'a>(Vec<&'a str>);
+
+impl<'a> ElfGroup<'a> {
+    fn new() -> Self {
+        Self(vec![])
+    }
+    fn parse_input(input: &'a str) -> Vec<Self> {
+        let mut elf_groups = vec![];
+        let mut group = ElfGroup::new();
+        for (i, line) in input.lines().enumerate() {
+            group.0.push(line);
+            if (i + 1) % 3 == 0 {
+                elf_groups.push(group.clone());
+                group.0.clear();
+            }
+        }
+        elf_groups
+    }
+
+    fn find_common_item_type(&self) -> HashSet<char> {
+        let line0 = *self.0.first().unwrap();
+        let line1 = *self.0.get(1).unwrap();
+        let line2 = *self.0.get(2).unwrap();
+        line0
+            .chars()
+            .filter(|c| line1.find(*c).is_some() && line2.find(*c).is_some())
+            .collect()
+    }
+
+    fn sum_priority(groups: &[Self]) -> i32 {
+        groups
+            .iter()
+            .map(|group| {
+                group
+                    .find_common_item_type()
+                    .iter()
+                    .map(|c| priority(*c))
+                    .sum::<i32>()
+            })
+            .sum()
+    }
+}
+
+fn priority(c: char) -> i32 {
+    match c {
+        c @ 'a'..='z' => c as i32 - 'a' as i32 + 1,
+        c @ 'A'..='Z' => c as i32 - 'A' as i32 + 27,
+        _ => unreachable!(),
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Rucksacks and groups borrow their items from the input, so keep it
+    /// whole once checked.
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        for line in input.lines() {
+            if let Some(c) = invalid_char(line, |c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    input,
+                    c,
+                    "expected an item a to z or A to Z",
+                ));
+            }
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected an even number of items",
+                ));
+            }
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        Rucksack::sum_priority(&Rucksack::parse_input(input))
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        ElfGroup::sum_priority(&ElfGroup::parse_input(input))
+    }
+}
+
+impl Generate for Solver {
+    /// `size` groups of three rucksacks. Each line uses letters of its own
+    /// besides the group's badge, so the badge is the only item the three
+    /// share, and only one item is in both compartments.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = vec![];
+        for _ in 0..size {
+            let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+            rng.shuffle(&mut items);
+            let badge = items[0];
+            for own in items[1..].chunks(17).take(3) {
+                let shared = own[0];
+                let fillers = rng.range(1..=8) as usize;
+                let mut halves = [&own[1..9], &own[9..17]].map(|pool| {
+                    let mut half = vec![shared];
+                    half.extend((0..fillers).map(|_| *rng.pick(pool)));
+                    half
+                });
+                let with_badge = rng.below(2);
+                halves[with_badge].push(badge);
+                halves[1 - with_badge].push(*rng.pick(&own[1 + 8 * (1 - with_badge)..][..8]));
+                for half in halves.iter_mut() {
+                    rng.shuffle(half);
+                }
+                lines.push(halves.concat().into_iter().collect::<String>());
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let rucksacks = Rucksack::parse_input(SAMPLE);
+        let sum = Rucksack::sum_priority(&rucksacks);
+        assert_eq!(sum, 157);
+    }
+    #[test]
+    fn test_part2_sample() {
+        let groups = ElfGroup::parse_input(SAMPLE);
+        let sum = ElfGroup::sum_priority(&groups);
+        assert_eq!(sum, 70);
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    interval::Interval,
+    parse::{number, split_once, ParseError},
+    solution::Solution,
+};
+
+/// Whether either range of sections holds the other one.
+fn fully_contain_or_in(s1: &Interval, s2: &Interval) -> bool {
+    s1.covers(s2) || s2.covers(s1)
+}
+
+pub struct Assignment(Vec<(Interval, Interval)>);
+
+impl Assignment {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let sections = |section| -> Result<Interval, ParseError> {
+            let (first, last) = split_once(input, section, "-")?;
+            Interval::inclusive(number(input, first)?, number(input, last)?)
+                .ok_or_else(|| ParseError::at(input, last, "expected a section below i64::MAX"))
+        };
+        input
+            .lines()
+            .map(|line| {
+                let (first, second) = split_once(input, line, ",")?;
+                Ok((sections(first)?, sections(second)?))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    fn count_duplicate<F>(&self, conditon: F) -> usize
+    where
+        F: Fn(&Interval, &Interval) -> bool,
+    {
+        self.0
+            .iter()
+            .filter(|&&pair| conditon(&pair.0, &pair.1))
+            .count()
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Assignment;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Assignment::parse_input(input)
+    }
+
+    fn part1(assignment: &Self::Input) -> usize {
+        assignment.count_duplicate(fully_contain_or_in)
+    }
+
+    fn part2(assignment: &Self::Input) -> usize {
+        assignment.count_duplicate(Interval::overlaps)
+    }
+}
+
+impl Generate for Solver {
+    /// `size` pairs of section assignments.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut sections = || {
+            let start = rng.range(1..=99);
+            format!("{start}-{}", rng.range(start..=99))
+        };
+        (0..size)
+            .map(|_| {
+                let first = sections();
+                format!("{first},{}", sections())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    static SAMPLE: &str = include_str!("sample");
+    use super::*;
+    #[test]
+    fn test_part1_sample() {
+        let assignment = Assignment::parse_input(SAMPLE).unwrap();
+        assert_eq!(assignment.count_duplicate(fully_contain_or_in), 2);
+    }
+
+    #[test]
+    fn test_part2_sample() {
+        let assignment = Assignment::parse_input(SAMPLE).unwrap();
+        assert_eq!(assignment.count_duplicate(Interval::overlaps), 4);
+    }
+}
@@ -0,0 +1,231 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, split_once, strip_prefix, ParseError},
+    solution::Solution,
+};
+
+enum Crane {
+    C9000,
+    C9001,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rearrangement {
+    cnt: i32,
+    from: i32,
+    to: i32,
+}
+
+impl Rearrangement {
+    fn new(cnt: i32, from: i32, to: i32) -> Self {
+        Self { cnt, from, to }
+    }
+
+    /// Parse `move <cnt> from <from> to <to>` out of `input`, with stacks
+    /// numbered from 1 to `stacks`.
+    fn parse_arrange(input: &str, s: &str, stacks: usize) -> Result<Self, ParseError> {
+        let s = strip_prefix(input, s, "move ")?;
+        let (cnt, s) = split_once(input, s, " from ")?;
+        let (from, to) = split_once(input, s, " to ")?;
+        let stack = |n| {
+            let i = number::<usize>(input, n)?;
+            if !(1..=stacks).contains(&i) {
+                return Err(ParseError::at(
+                    input,
+                    n,
+                    format!("expected a stack 1 to {stacks}"),
+                ));
+            }
+            Ok(i as i32 - 1)
+        };
+        Ok(Self::new(number(input, cnt)?, stack(from)?, stack(to)?))
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Crates {
+    stacks: Vec<Vec<char>>,
+    rearrangement: Vec<Rearrangement>,
+}
+
+impl Crates {
+    fn new() -> Self {
+        Self {
+            stacks: vec![],
+            rearrangement: vec![],
+        }
+    }
+
+    // stack_index starts from 0
+    fn add_crate(&mut self, stack_index: usize, c: char) {
+        if (stack_index + 1) > self.stacks.len() {
+            for _ in 0..(stack_index + 1) - self.stacks.len() {
+                self.stacks.push(vec![]);
+            }
+        }
+        match self.stacks.get_mut(stack_index) {
+            Some(stack) => stack.insert(0, c),
+            None => unreachable!(),
+        }
+    }
+
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut crates = Self::new();
+        let mut stack_end = false;
+        for line in input.lines() {
+            if line.is_empty() {
+                stack_end = true;
+            } else if stack_end {
+                // parse arrangement
+                let arrange = Rearrangement::parse_arrange(input, line, crates.stacks.len())?;
+                crates.rearrangement.push(arrange);
+            } else {
+                // parse stacks
+                for (i, c) in line.chars().enumerate() {
+                    if !stack_end && i % 4 == 1 && c.is_ascii_alphabetic() {
+                        crates.add_crate(i / 4, c)
+                    }
+                }
+            }
+        }
+        if !stack_end {
+            return Err(ParseError::end(
+                input,
+                "expected a blank line before the moves",
+            ));
+        }
+        Ok(crates)
+    }
+
+    fn rearrangement_procedure_crane_9000(&mut self, arrange: Rearrangement) {
+        let cnt = arrange.cnt;
+        let from = arrange.from as usize;
+        let to = arrange.to as usize;
+        for _ in 0..cnt {
+            if let Some(stack) = self.stacks.get_mut(from) {
+                if let Some(elem) = stack.pop() {
+                    if let Some(stack) = self.stacks.get_mut(to) {
+                        stack.push(elem);
+                    }
+                }
+            }
+        }
+    }
+    fn rearrangement_procedure_crane_9001(&mut self, arrange: Rearrangement) {
+        let cnt = arrange.cnt;
+        let from = arrange.from as usize;
+        let to = arrange.to as usize;
+        if let Some(stack) = self.stacks.get_mut(from) {
+            let mut v = vec![];
+            for _ in 0..cnt {
+                if let Some(elem) = stack.pop() {
+                    v.insert(0, elem);
+                }
+            }
+            if let Some(stack) = self.stacks.get_mut(to) {
+                stack.extend(v);
+            }
+        }
+    }
+
+    fn rearrangement_procedure(&mut self, crane: Crane) {
+        self.rearrangement
+            .clone()
+            .iter()
+            .for_each(|arrange| match crane {
+                Crane::C9000 => self.rearrangement_procedure_crane_9000(*arrange),
+                Crane::C9001 => self.rearrangement_procedure_crane_9001(*arrange),
+            });
+    }
+
+    fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect::<String>()
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Crates;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Crates::parse_input(input)
+    }
+
+    fn part1(crates: &Self::Input) -> String {
+        let mut crates = crates.clone();
+        crates.rearrangement_procedure(Crane::C9000);
+        crates.top_crates()
+    }
+
+    fn part2(crates: &Self::Input) -> String {
+        let mut crates = crates.clone();
+        crates.rearrangement_procedure(Crane::C9001);
+        crates.top_crates()
+    }
+}
+
+impl Generate for Solver {
+    /// Up to nine stacks of crates and `size` moves between them.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stacks = (0..rng.range(3..=9))
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut drawing = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| stack.get(level).map_or("   ".into(), |c| format!("[{c}]")))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+        let numbers = (1..=stacks.len())
+            .map(|i| format!(" {i} "))
+            .collect::<Vec<_>>();
+        drawing.push(numbers.join(" "));
+        let moves = (0..size).map(|_| {
+            let filled = (0..stacks.len())
+                .filter(|&i| !stacks[i].is_empty())
+                .collect::<Vec<_>>();
+            let from = *rng.pick(&filled);
+            let to = (from + 1 + rng.below(stacks.len() - 1)) % stacks.len();
+            let count = rng.range(1..=stacks[from].len() as i64) as usize;
+            let keep = stacks[from].len() - count;
+            let moved = stacks[from].split_off(keep);
+            stacks[to].extend(moved);
+            format!("move {count} from {} to {}", from + 1, to + 1)
+        });
+        drawing.push(String::new());
+        drawing.extend(moves);
+        drawing.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let mut crates = Crates::parse_input(SAMPLE).unwrap();
+        crates.rearrangement_procedure(Crane::C9000);
+        assert_eq!("CMZ".to_string(), crates.top_crates());
+    }
+    #[test]
+    fn test_part2_sample() {
+        let mut crates = Crates::parse_input(SAMPLE).unwrap();
+        crates.rearrangement_procedure(Crane::C9001);
+        assert_eq!("MCD".to_string(), crates.top_crates());
+    }
+}
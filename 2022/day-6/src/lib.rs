@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Clone, Copy)]
+enum MarkerType {
+    StartPacket,
+    StartMessage,
+}
+
+impl MarkerType {
+    fn marker_len(self) -> usize {
+        match self {
+            Self::StartPacket => 4,
+            Self::StartMessage => 14,
+        }
+    }
+}
+
+struct Buffer<'a>(&'a str);
+
+impl<'a> Buffer<'a> {
+    fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    fn marker_postion(&self, marker_type: MarkerType) -> usize {
+        let mut character_pos_map = HashMap::<char, usize>::new();
+        let mut curr_marker_len = 0;
+        for (i, c) in self.0.chars().enumerate() {
+            if let Some(pos) = character_pos_map.get_mut(&c) {
+                let old_pos = *pos;
+                *pos = i;
+                curr_marker_len = i - old_pos;
+                character_pos_map.retain(|_, p| *p > old_pos);
+            } else {
+                curr_marker_len += 1;
+                if curr_marker_len >= marker_type.marker_len() {
+                    return i + 1;
+                }
+                character_pos_map.insert(c, i);
+            }
+        }
+        0
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        Buffer::new(input).marker_postion(MarkerType::StartPacket)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        Buffer::new(input).marker_postion(MarkerType::StartMessage)
+    }
+}
+
+impl Generate for Solver {
+    /// A datastream of `size` characters (at least 32), with no marker
+    /// before its second half.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(32);
+        let mut letters = ('a'..='z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        // too few letters to hold a marker: three, then thirteen
+        let mut stream = (0..size / 2)
+            .map(|_| *rng.pick(&letters[..3]))
+            .collect::<String>();
+        stream.extend((0..size / 4).map(|_| *rng.pick(&letters[..13])));
+        rng.shuffle(&mut letters);
+        stream.extend(&letters[..14]);
+        stream.extend((stream.len()..size).map(|_| *rng.pick(&letters)));
+        stream
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let sample_expect_answer = [7, 5, 6, 10, 11];
+        for (i, line) in SAMPLE.lines().enumerate() {
+            let buffer = Buffer::new(line);
+            assert_eq!(
+                sample_expect_answer[i],
+                buffer.marker_postion(MarkerType::StartPacket)
+            );
+        }
+    }
+    #[test]
+    fn test_part2_sample() {
+        let sample_expect_answer = [19, 23, 23, 29, 26];
+        for (i, line) in SAMPLE.lines().enumerate() {
+            let buffer = Buffer::new(line);
+            assert_eq!(
+                sample_expect_answer[i],
+                buffer.marker_postion(MarkerType::StartMessage)
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use std::{cell::RefCell, rc::Rc};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    parse::{number, split_once, strip_prefix, ParseError},
+    solution::Solution,
+};
+
+const DIR_MAX_SIZE: usize = 100000;
+const DISK_TOTAL_SPACE: usize = 70000000;
+const DISK_SPACE_NEED: usize = 30000000;
+/// A file, only its size mattering.
+#[derive(Debug)]
+struct File {
+    size: usize,
+}
+
+#[derive(Debug)]
+struct Dir {
+    name: String,
+    files: Vec<File>,
+    dirs: Vec<Rc<RefCell<Dir>>>,
+}
+
+impl Dir {
+    fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            files: vec![],
+            dirs: vec![],
+        }
+    }
+
+    fn parse_input(input: &str) -> Result<Rc<RefCell<Self>>, ParseError> {
+        let mut dir_stack = vec![];
+        let root = "/";
+        let dir = Rc::new(RefCell::new(Dir::new(root)));
+        dir_stack.push(dir);
+        for line in input.lines() {
+            if let Some(cmd) = line.strip_prefix("$ ") {
+                if cmd == "ls" {
+                    continue;
+                }
+                let dir_name = strip_prefix(input, cmd, "cd ")?;
+                if dir_name == root {
+                    dir_stack.truncate(1);
+                } else if dir_name == ".." {
+                    if dir_stack.len() == 1 {
+                        return Err(ParseError::at(input, dir_name, "already at the root"));
+                    }
+                    dir_stack.pop();
+                } else {
+                    let cd_dir = dir_stack
+                        .last()
+                        .unwrap()
+                        .borrow()
+                        .dirs
+                        .iter()
+                        .find(|dir| dir.borrow().name == dir_name)
+                        .cloned();
+                    match cd_dir {
+                        Some(dir) => dir_stack.push(dir),
+                        None => {
+                            return Err(ParseError::at(
+                                input,
+                                dir_name,
+                                "expected a directory listed before",
+                            ))
+                        }
+                    }
+                }
+            } else if let Some(dir_name) = line.strip_prefix("dir ") {
+                let dir = Rc::new(RefCell::new(Dir::new(dir_name)));
+                dir_stack.last().unwrap().borrow_mut().dirs.push(dir);
+            } else {
+                let (file_size, _) = split_once(input, line, " ")?;
+                let file = File {
+                    size: number(input, file_size)?,
+                };
+                dir_stack.last().unwrap().borrow_mut().files.push(file);
+            }
+        }
+        Ok(dir_stack.first().unwrap().to_owned())
+    }
+
+    fn dir_size(&self, size_vec: &mut Vec<usize>) -> usize {
+        let mut dir_size = self.files.iter().map(|file| file.size).sum();
+        for dir in self.dirs.iter() {
+            dir_size += dir.borrow().dir_size(size_vec);
+        }
+        size_vec.push(dir_size);
+        dir_size
+    }
+
+    fn part1(size_vec: &[usize]) -> usize {
+        size_vec
+            .iter()
+            .filter(|size| **size <= DIR_MAX_SIZE)
+            .sum::<usize>()
+    }
+
+    fn part2(size_vec: &mut [usize]) -> usize {
+        size_vec.sort();
+        let used_space = size_vec.last().unwrap();
+        let space_need = DISK_SPACE_NEED - (DISK_TOTAL_SPACE - used_space);
+        match size_vec.binary_search(&space_need) {
+            Ok(i) | Err(i) => size_vec[i],
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Total size of every directory, the root being the last one.
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARSE_SOLVES: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let dir = Dir::parse_input(input)?;
+        let mut size_vec = vec![];
+        let used = dir.borrow().dir_size(&mut size_vec);
+        if used <= DISK_TOTAL_SPACE - DISK_SPACE_NEED || used > DISK_TOTAL_SPACE {
+            return Err(ParseError::end(
+                input,
+                format!(
+                    "expected files too big for the update, more than {} and at most {}",
+                    DISK_TOTAL_SPACE - DISK_SPACE_NEED,
+                    DISK_TOTAL_SPACE
+                ),
+            ));
+        }
+        Ok(size_vec)
+    }
+
+    fn part1(size_vec: &Self::Input) -> usize {
+        Dir::part1(size_vec)
+    }
+
+    fn part2(size_vec: &Self::Input) -> usize {
+        Dir::part2(&mut size_vec.clone())
+    }
+}
+
+/// A made up file or directory name.
+fn name(rng: &mut Rng) -> String {
+    let mut name = (0..rng.range(1..=8))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect::<String>();
+    if rng.chance(0.3) {
+        let extension = *rng.pick(&[".txt", ".dat", ".log"]);
+        name.push_str(extension);
+    }
+    name
+}
+
+/// A directory made up for a transcript.
+#[derive(Default)]
+struct Listing {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(String, usize)>,
+}
+
+/// The commands listing `dir` and everything below it.
+fn browse(rng: &mut Rng, dirs: &[Listing], dir: usize, lines: &mut Vec<String>) {
+    lines.push("$ ls".into());
+    let mut entries = dirs[dir]
+        .dirs
+        .iter()
+        .map(|&d| format!("dir {}", dirs[d].name))
+        .chain(
+            dirs[dir]
+                .files
+                .iter()
+                .map(|(f, size)| format!("{size} {f}")),
+        )
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut entries);
+    lines.extend(entries);
+    for &sub in &dirs[dir].dirs {
+        lines.push(format!("$ cd {}", dirs[sub].name));
+        browse(rng, dirs, sub, lines);
+        lines.push("$ cd ..".into());
+    }
+}
+
+impl Generate for Solver {
+    /// A terminal session browsing `size` directories (at least 1), which
+    /// together use between 41 and 69 million of the 70 million available.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = size.max(1);
+        let mut dirs = (0..count).map(|_| Listing::default()).collect::<Vec<_>>();
+        let mut files = 0;
+        for dir in 0..count {
+            if dir > 0 {
+                let parent = rng.below(dir);
+                let taken =
+                    |name: &String| dirs[parent].dirs.iter().any(|&d| dirs[d].name == *name);
+                let mut dir_name = name(rng);
+                while taken(&dir_name) {
+                    dir_name.push((b'a' + rng.below(26) as u8) as char);
+                }
+                dirs[dir].name = dir_name;
+                dirs[parent].dirs.push(dir);
+            }
+            let count = rng.range(0..=4) as usize;
+            dirs[dir].files = (0..count).map(|_| (name(rng), 0)).collect();
+            files += count;
+        }
+        // keep the files under 30 million, then fill the disk from the root
+        let largest = (30_000_000 / files.max(1)).clamp(1, 100_000) as i64;
+        let mut used = 0;
+        for (_, size) in dirs.iter_mut().flat_map(|d| d.files.iter_mut()) {
+            *size = rng.range(1..=largest) as usize;
+            used += *size;
+        }
+        let total = rng.range(41_000_000..=69_000_000) as usize;
+        dirs[0].files.push((name(rng), total - used));
+        let mut lines = vec!["$ cd /".to_string()];
+        browse(rng, &dirs, 0, &mut lines);
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let dir = Dir::parse_input(SAMPLE).unwrap();
+        let mut size_vec = vec![];
+        dir.borrow().dir_size(&mut size_vec);
+        assert_eq!(95437_usize, Dir::part1(&size_vec))
+    }
+    #[test]
+    fn test_part2_sample() {
+        let dir = Dir::parse_input(SAMPLE).unwrap();
+        let mut size_vec = vec![];
+        dir.borrow().dir_size(&mut size_vec);
+        assert_eq!(24933642_usize, Dir::part2(&mut size_vec));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::{
+    generate::{Generate, Rng},
+    grid::{Grid, NEIGHBORS4},
+    parse::ParseError,
+    solution::Solution,
+};
+
+pub struct TreeMap(Grid<u8>);
+
+impl TreeMap {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(Grid::parse_digits(input)?))
+    }
+
+    fn visible_trees(&self) -> (i32, i32) {
+        let mut visible = 0;
+        let mut highest_scenic_score = 0;
+        for (pos, tree) in self.0.iter() {
+            let mut is_visible = false;
+            let mut score = 1;
+            // look up, down, left and right until the edge or a tree at least as tall
+            for dir in NEIGHBORS4 {
+                let mut view = 0;
+                let mut blocked = false;
+                for p in self.0.ray(pos, dir) {
+                    view += 1;
+                    if self.0[p] >= *tree {
+                        blocked = true;
+                        break;
+                    }
+                }
+                is_visible |= !blocked;
+                score *= view;
+            }
+            if is_visible {
+                visible += 1;
+            }
+            if score > highest_scenic_score {
+                highest_scenic_score = score;
+            };
+        }
+        (visible, highest_scenic_score)
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = TreeMap;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        TreeMap::parse_input(input)
+    }
+
+    fn part1(tree_map: &Self::Input) -> i32 {
+        tree_map.visible_trees().0
+    }
+
+    fn part2(tree_map: &Self::Input) -> i32 {
+        tree_map.visible_trees().1
+    }
+}
+
+impl Generate for Solver {
+    /// A `size` by `size` map of tree heights.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| rng.range(0..=9).to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    #[test]
+    fn test_part1_sample() {
+        let tree_map = TreeMap::parse_input(SAMPLE).unwrap();
+        let (visible_cnt, hightest) = tree_map.visible_trees();
+        assert_eq!(21, visible_cnt);
+        assert_eq!(8, hightest);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    frames::{Frame, Recorder, Visualise},
+    generate::{Generate, Rng},
+    grid::Grid,
+    parse::{number, split_once, ParseError},
+    point::{Bounds, Dir, Point2},
+    solution::Solution,
+};
+
+/// One motion of the head: a direction and a number of steps.
+#[derive(Debug, Clone, Copy)]
+pub struct Motion(Dir, u32);
+
+fn parse_motions(input: &str) -> Result<Vec<Motion>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (dir, steps) = split_once(input, line, " ")?;
+            let dir = match dir {
+                "R" => Dir::Right,
+                "L" => Dir::Left,
+                "U" => Dir::Up,
+                "D" => Dir::Down,
+                _ => return Err(ParseError::at(input, dir, "expected R, L, U or D")),
+            };
+            Ok(Motion(dir, number(input, steps)?))
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct Rope {
+    head: Point2,
+    tail: Vec<Point2>,
+    tail_visited: HashSet<Point2>,
+}
+
+impl Rope {
+    fn new(tail_num: usize) -> Self {
+        let mut rope = Rope {
+            tail: vec![Point2::ORIGIN; tail_num],
+            ..Default::default()
+        };
+        rope.tail_visited.insert(Point2::ORIGIN);
+        rope
+    }
+
+    fn move_tail(&mut self) {
+        let tail_len = self.tail.len();
+        let mut head = self.head;
+        for (i, tail) in self.tail.iter_mut().enumerate() {
+            // still touching, so are the knots behind
+            if head.chebyshev(*tail) <= 1 {
+                return;
+            }
+            *tail += (head - *tail).signum();
+            head = *tail;
+            if i == tail_len - 1 {
+                self.tail_visited.insert(*tail);
+            }
+        }
+    }
+
+    fn step(&mut self, dir: Dir) {
+        self.head += dir.offset();
+        self.move_tail();
+    }
+
+    /// Apply `motions`, calling `on_step` after every step of the head.
+    fn simulating(&mut self, motions: &[Motion], mut on_step: impl FnMut(&Self)) {
+        motions.iter().for_each(|&Motion(dir, steps)| {
+            for _ in 0..steps {
+                self.step(dir);
+                on_step(self);
+            }
+        });
+    }
+
+    /// The area of `bounds` with up on top: the head as `H`, the knots as
+    /// their number and the positions the last one visited as `#`.
+    fn frame(&self, bounds: Bounds<Point2>) -> Frame {
+        let size = bounds.size();
+        Grid::from_fn(size.y as usize, size.x as usize, |(row, col)| {
+            let pos = Point2::new(bounds.min.x + col as i64, bounds.max.y - row as i64);
+            if pos == self.head {
+                return 'H';
+            }
+            match self.tail.iter().position(|&knot| knot == pos) {
+                Some(i) => char::from_digit(i as u32 + 1, 10).unwrap_or('+'),
+                None if self.tail_visited.contains(&pos) => '#',
+                None => '.',
+            }
+        })
+    }
+}
+
+/// The box the head goes through, the knots never leave it.
+fn head_bounds(motions: &[Motion]) -> Bounds<Point2> {
+    let mut head = Point2::ORIGIN;
+    let mut bounds = Bounds::new(head);
+    for &Motion(dir, steps) in motions {
+        head += dir.offset() * steps as i64;
+        bounds.include(head);
+    }
+    bounds
+}
+
+/// Number of positions visited by the last knot of a rope with `tail_num`
+/// knots behind the head.
+fn tail_visited(motions: &[Motion], tail_num: usize) -> usize {
+    let mut rope = Rope::new(tail_num);
+    rope.simulating(motions, |_| {});
+    rope.tail_visited.len()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Motion>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_motions(input)
+    }
+
+    fn part1(motions: &Self::Input) -> usize {
+        tail_visited(motions, 1)
+    }
+
+    fn part2(motions: &Self::Input) -> usize {
+        tail_visited(motions, 9)
+    }
+}
+
+impl Visualise for Solver {
+    /// The rope of part 2 following its head.
+    fn visualise(motions: &Self::Input, recorder: &mut Recorder) {
+        let bounds = head_bounds(motions);
+        let mut rope = Rope::new(9);
+        recorder.record(|| rope.frame(bounds));
+        rope.simulating(motions, |rope| recorder.record(|| rope.frame(bounds)));
+    }
+}
+
+impl Generate for Solver {
+    /// `size` motions of the head.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}", rng.pick(&["R", "L", "U", "D"]), rng.range(1..=20)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+    static SAMPLE2: &str = include_str!("sample2");
+    #[test]
+    fn test_part1_sample() {
+        let mut rope = Rope::new(1);
+        rope.simulating(&parse_motions(SAMPLE).unwrap(), |_| {});
+        assert_eq!(13, rope.tail_visited.len());
+    }
+    #[test]
+    fn test_part2_sample() {
+        let mut rope = Rope::new(9);
+        rope.simulating(&parse_motions(SAMPLE).unwrap(), |_| {});
+        assert_eq!(1, rope.tail_visited.len());
+        let mut rope = Rope::new(9);
+        rope.simulating(&parse_motions(SAMPLE2).unwrap(), |_| {});
+        assert_eq!(36, rope.tail_visited.len());
+    }
+    #[test]
+    fn test_visualise() {
+        let mut recorder = Recorder::new(1, usize::MAX);
+        Solver::visualise(&parse_motions(SAMPLE).unwrap(), &mut recorder);
+        let frames = recorder.into_frames();
+        // the start, then one frame per step of the head
+        assert_eq!(frames.len(), 25);
+        assert_eq!(
+            frames[0].to_string(),
+            "......\n......\n......\n......\nH....."
+        );
+        assert_eq!(
+            frames[24].to_string(),
+            "......\n......\n.1H3..\n.5....\n6....."
+        );
+    }
+}
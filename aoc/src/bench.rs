@@ -0,0 +1,192 @@
+use std::{collections::BTreeMap, fmt::Write, path::Path, time::Duration};
+
+use aoc_common::{input::InputError, solution::Timings};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Day;
+
+/// Header of the table printed by [`rows`].
+pub const HEADER: &str = "day       stage      median         min         max  vs baseline";
+
+/// Spread of the timings of one stage over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` without any sample.
+    pub fn of(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            n if n % 2 == 1 => samples[n / 2],
+            n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+        Some(Self {
+            median,
+            min: samples[0],
+            max: samples[n - 1],
+        })
+    }
+}
+
+/// The timings of a day over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayStats {
+    /// Stats of parse, part 1 and part 2, in that order.
+    pub stages: [Stats; 3],
+    /// See [`aoc_common::solution::Solution::PARSE_SOLVES`].
+    pub parse_solves: bool,
+}
+
+const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Solve `input` with `day` `iterations` times, at least once.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayStats, InputError> {
+    let runs = (0..iterations.max(1))
+        .map(|_| Ok(day.solve_timed(input)?.1))
+        .collect::<Result<Vec<Timings>, InputError>>()?;
+    let stage = |f: fn(&Timings) -> Duration| Stats::of(runs.iter().map(f).collect()).unwrap();
+    Ok(DayStats {
+        stages: [stage(|t| t.parse), stage(|t| t.part1), stage(|t| t.part2)],
+        parse_solves: runs[0].parse_solves,
+    })
+}
+
+/// Median of each stage of a day in nanoseconds, as saved in a baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Medians {
+    parse: u64,
+    part1: u64,
+    part2: u64,
+}
+
+impl Medians {
+    pub fn of(stats: &DayStats) -> Self {
+        let nanos = |s: &Stats| s.median.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            parse: nanos(&stats.stages[0]),
+            part1: nanos(&stats.stages[1]),
+            part2: nanos(&stats.stages[2]),
+        }
+    }
+
+    fn stages(&self) -> [Duration; 3] {
+        [self.parse, self.part1, self.part2].map(Duration::from_nanos)
+    }
+}
+
+/// Medians of earlier runs, one table per day:
+///
+/// ```toml
+/// ["2021 day 15"]
+/// parse = 48200
+/// part1 = 1530000
+/// part2 = 41800000
+/// ```
+pub type Baseline = BTreeMap<String, Medians>;
+
+/// Key of a day in a [`Baseline`].
+pub fn key(day: &Day) -> String {
+    format!("{} day {}", day.year, day.day)
+}
+
+pub fn load(path: &Path) -> Result<Baseline, String> {
+    let s = std::fs::read_to_string(path).map_err(|e| format!("read {}: {e}", path.display()))?;
+    toml::from_str(&s).map_err(|e| format!("parse {}: {e}", path.display()))
+}
+
+pub fn save(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    let s = toml::to_string(baseline).map_err(|e| format!("serialize baseline: {e}"))?;
+    std::fs::write(path, s).map_err(|e| format!("write {}: {e}", path.display()))
+}
+
+/// How a median compares with the baseline one.
+fn change(current: Duration, baseline: Duration) -> String {
+    let (current, baseline) = (current.as_secs_f64(), baseline.as_secs_f64());
+    if current == 0.0 || baseline == 0.0 {
+        "-".to_string()
+    } else if current <= baseline {
+        format!("{:.2}x faster", baseline / current)
+    } else {
+        format!("{:.2}x slower", current / baseline)
+    }
+}
+
+/// The table rows of a day, one per stage, compared with `baseline` when the
+/// day has one. A parse that does the solving both parts share says so, its
+/// time would otherwise pass for slow parsing.
+pub fn rows(day: &Day, stats: &DayStats, baseline: Option<&Medians>) -> String {
+    let label = key(day).replace(" day ", " ");
+    let mut out = String::new();
+    for (i, (stage, s)) in STAGES.iter().zip(&stats.stages).enumerate() {
+        let vs = baseline.map_or(String::new(), |b| change(s.median, b.stages()[i]));
+        let [median, min, max] = [s.median, s.min, s.max].map(|d| format!("{d:.1?}"));
+        let label = if i == 0 { label.as_str() } else { "" };
+        let note = if i == 0 && stats.parse_solves {
+            "(includes solving)"
+        } else {
+            ""
+        };
+        let row = format!("{label:<9} {stage:<5} {median:>11} {min:>11} {max:>11}  {vs}  {note}");
+        writeln!(out, "{}", row.trim_end()).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::of(vec![]), None);
+        let odd = Stats::of(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((odd.median, odd.min, odd.max), (ms(3), ms(1), ms(5)));
+        let even = Stats::of(vec![ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!((even.median, even.min, even.max), (ms(3), ms(1), ms(9)));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = DayStats {
+            stages: [ms(1), ms(20), ms(300)].map(|d| Stats::of(vec![d]).unwrap()),
+            parse_solves: false,
+        };
+        let mut baseline = Baseline::new();
+        baseline.insert("2021 day 15".to_string(), Medians::of(&stats));
+        let s = toml::to_string(&baseline).unwrap();
+        assert_eq!(toml::from_str::<Baseline>(&s).unwrap(), baseline);
+        assert_eq!(baseline["2021 day 15"].stages(), [ms(1), ms(20), ms(300)]);
+    }
+
+    #[test]
+    fn test_rows() {
+        let day = &crate::registry::DAYS[0];
+        let mut stats = DayStats {
+            stages: [ms(1), ms(20), ms(300)].map(|d| Stats::of(vec![d]).unwrap()),
+            parse_solves: false,
+        };
+        assert!(!rows(day, &stats, None).contains("includes solving"));
+        stats.parse_solves = true;
+        let rows = rows(day, &stats, None);
+        let parse = rows.lines().next().unwrap();
+        assert!(parse.contains("parse") && parse.ends_with("(includes solving)"));
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(ms(10), ms(30)), "3.00x faster");
+        assert_eq!(change(ms(15), ms(10)), "1.50x slower");
+        assert_eq!(change(ms(0), ms(10)), "-");
+    }
+}
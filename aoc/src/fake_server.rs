@@ -0,0 +1,68 @@
+//! A stand-in for the puzzle website, for tests of the commands talking to it.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use tiny_http::{Header, Response, Server};
+
+use crate::fetch::Fetcher;
+
+/// A request as the server saw it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub type Seen = Arc<Mutex<Vec<Request>>>;
+
+/// Serve `responses` in order, one per request, then stop. Returns the base
+/// URL of the server and the requests it got.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Seen, JoinHandle<()>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+    let seen = Seen::default();
+    let log = Arc::clone(&seen);
+    let handle = std::thread::spawn(move || {
+        for (status, body) in responses {
+            let mut request = server.recv().unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            let mut sent = String::new();
+            request.as_reader().read_to_string(&mut sent).unwrap();
+            log.lock().unwrap().push(Request {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie,
+                body: sent,
+            });
+            let header = Header::from_bytes("Content-Type", "text/html").unwrap();
+            let response = Response::from_string(body)
+                .with_status_code(status)
+                .with_header(header);
+            request.respond(response).unwrap();
+        }
+    });
+    (base_url, seen, handle)
+}
+
+/// A client of `base_url` with a fresh cache directory named after the test
+/// and no rate limit.
+pub fn fetcher(name: &str, base_url: &str) -> Fetcher {
+    let cache_dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    Fetcher {
+        base_url: base_url.to_string(),
+        session: Some("abc123\n".to_string()),
+        cache_dir,
+        min_interval: Duration::ZERO,
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Shortest wait between two requests to the server, whichever process made
+/// the previous one.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+pub const USER_AGENT: &str = "github.com/kanjie128/aoc";
+
+// touched before every request, its mtime is the time of the last one
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+/// `config.toml` under the per-user config directory, every field optional:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// cache_dir = "/home/me/.cache/aoc"
+/// min_interval_secs = 5
+/// ```
+///
+/// `AOC_SESSION`, `AOC_BASE_URL` and `AOC_CACHE_DIR` take precedence.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    session: Option<String>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    min_interval_secs: Option<u64>,
+}
+
+impl Config {
+    /// Where the config is looked up, `AOC_CONFIG` overriding the default.
+    pub fn path() -> Option<PathBuf> {
+        match std::env::var_os("AOC_CONFIG") {
+            Some(path) => Some(path.into()),
+            None => dirs::config_dir().map(|dir| dir.join("aoc").join("config.toml")),
+        }
+    }
+
+    /// The config at `path`, empty if there is no such file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("read {}: {e}", path.display())),
+        };
+        toml::from_str(&s).map_err(|e| format!("parse {}: {e}", path.display()))
+    }
+}
+
+/// Downloads puzzle inputs into a cache laid out as `<year>/day-<n>` files,
+/// so the cache directory can be given to `--input` as is.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in browser, only needed for
+    /// days not cached yet.
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+/// How [`Fetcher::fetch`] got the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetcher {
+    /// A fetcher from `config`, overridden by the environment.
+    pub fn from_config(config: Config) -> Result<Self, String> {
+        let env = |name| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let cache_dir = match env("AOC_CACHE_DIR").map(PathBuf::from).or(config.cache_dir) {
+            Some(dir) => dir,
+            None => dirs::cache_dir()
+                .ok_or("no cache directory for this user, set AOC_CACHE_DIR")?
+                .join("aoc"),
+        };
+        Ok(Self {
+            base_url: env("AOC_BASE_URL")
+                .or(config.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session: env("AOC_SESSION").or(config.session),
+            cache_dir,
+            min_interval: config
+                .min_interval_secs
+                .map_or(DEFAULT_MIN_INTERVAL, Duration::from_secs),
+        })
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day-{day}"))
+    }
+
+    /// The input of `year` `day`, downloaded unless it is cached already.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<Fetched, String> {
+        let path = self.cache_path(year, day);
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+        let session = self.session()?;
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).map_err(|e| format!("create {}: {e}", dir.display()))?;
+        self.wait_turn()?;
+
+        let url = format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        );
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        let body = match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("read {url}: {e}"))?,
+            Err(ureq::Error::Status(404, _)) => {
+                return Err(format!(
+                    "no input for {year} day {day} yet (404 from {url})"
+                ))
+            }
+            Err(ureq::Error::Status(code @ (400 | 401 | 403), _)) => {
+                return Err(format!("session token rejected ({code} from {url})"))
+            }
+            Err(ureq::Error::Status(code, _)) => return Err(format!("{code} from {url}")),
+            Err(e) => return Err(format!("get {url}: {e}")),
+        };
+        if body.is_empty() {
+            return Err(format!("empty input from {url}"));
+        }
+
+        // write aside then rename, an interrupted download must not look cached
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, body).map_err(|e| format!("write {}: {e}", partial.display()))?;
+        std::fs::rename(&partial, &path).map_err(|e| format!("write {}: {e}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// The session token, accepting a pasted `session=...` cookie as well.
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .map(|s| s.trim().trim_start_matches("session="))
+            .ok_or_else(|| {
+                "no session token, set AOC_SESSION or `session` in the config file".to_string()
+            })
+    }
+
+    /// Sleep until `min_interval` has passed since the last request, then
+    /// record this one.
+    pub fn wait_turn(&self) -> Result<(), String> {
+        let stamp = self.cache_dir.join(LAST_REQUEST_FILE);
+        let last = std::fs::metadata(&stamp).and_then(|m| m.modified());
+        if let Ok(since) = last.map(|t| SystemTime::now().duration_since(t).unwrap_or_default()) {
+            if since < self.min_interval {
+                std::thread::sleep(self.min_interval - since);
+            }
+        }
+        std::fs::write(&stamp, "").map_err(|e| format!("write {}: {e}", stamp.display()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::time::Instant;
+
+    use crate::fake_server::{fetcher, serve, Request};
+
+    #[test]
+    fn test_fetch_then_cached() {
+        let (base_url, seen, server) = serve(vec![(200, "1\n2\n")]);
+        let fetcher = fetcher("cached", &base_url);
+        let path = fetcher.cache_path(2021, 15);
+        assert_eq!(
+            fetcher.fetch(2021, 15),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n");
+        // no request and no token needed once cached
+        let offline = Fetcher {
+            session: None,
+            ..fetcher.clone()
+        };
+        assert_eq!(offline.fetch(2021, 15), Ok(Fetched::Cached(path)));
+        server.join().unwrap();
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [Request {
+                method: "GET".to_string(),
+                url: "/2021/day/15/input".to_string(),
+                cookie: Some("session=abc123".to_string()),
+                body: String::new(),
+            }]
+        );
+        std::fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_failures_are_not_cached() {
+        let (base_url, _, server) = serve(vec![(404, "not yet"), (400, "log in"), (200, "")]);
+        let fetcher = fetcher("failures", &base_url);
+        let err = fetcher.fetch(2022, 25).unwrap_err();
+        assert!(err.starts_with("no input for 2022 day 25 yet"), "{err}");
+        let err = fetcher.fetch(2022, 25).unwrap_err();
+        assert!(err.starts_with("session token rejected (400"), "{err}");
+        let err = fetcher.fetch(2022, 25).unwrap_err();
+        assert!(err.starts_with("empty input"), "{err}");
+        assert!(!fetcher.cache_path(2022, 25).exists());
+        let anonymous = Fetcher {
+            session: None,
+            ..fetcher.clone()
+        };
+        assert!(anonymous
+            .fetch(2022, 25)
+            .unwrap_err()
+            .contains("AOC_SESSION"));
+        server.join().unwrap();
+        std::fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (base_url, _, server) = serve(vec![(200, "1"), (200, "2")]);
+        let fetcher = Fetcher {
+            min_interval: Duration::from_millis(300),
+            ..fetcher("rate", &base_url)
+        };
+        fetcher.fetch(2021, 1).unwrap();
+        let start = Instant::now();
+        fetcher.fetch(2021, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(250));
+        server.join().unwrap();
+        std::fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_config() {
+        let config: Config =
+            toml::from_str("session = \"abc\"\nbase_url = \"http://localhost:8080\"\n").unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert!(toml::from_str::<Config>("token = \"abc\"").is_err());
+        assert_eq!(
+            Config::load(Path::new("no/such/config.toml")),
+            Ok(Config::default())
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use aoc_common::generate::generate_input;
+
+/// A type erased [`generate_input`].
+pub type GenerateFn = fn(u64, usize) -> String;
+
+/// A day that can make up inputs of any size.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    pub generate: GenerateFn,
+}
+
+/// Every day with a generator, ordered by year then day.
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2020,
+        day: 1,
+        generate: generate_input::<aoc_2020_day_1::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 1,
+        generate: generate_input::<aoc_2021_day_1::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 2,
+        generate: generate_input::<aoc_2021_day_2::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 3,
+        generate: generate_input::<aoc_2021_day_3::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 4,
+        generate: generate_input::<aoc_2021_day_4::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 5,
+        generate: generate_input::<aoc_2021_day_5::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 6,
+        generate: generate_input::<aoc_2021_day_6::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 7,
+        generate: generate_input::<aoc_2021_day_7::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 8,
+        generate: generate_input::<aoc_2021_day_8::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 9,
+        generate: generate_input::<aoc_2021_day_9::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 10,
+        generate: generate_input::<aoc_2021_day_10::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 11,
+        generate: generate_input::<aoc_2021_day_11::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 12,
+        generate: generate_input::<aoc_2021_day_12::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 13,
+        generate: generate_input::<aoc_2021_day_13::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 14,
+        generate: generate_input::<aoc_2021_day_14::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 15,
+        generate: generate_input::<aoc_2021_day_15::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 16,
+        generate: generate_input::<aoc_2021_day_16::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 17,
+        generate: generate_input::<aoc_2021_day_17::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 18,
+        generate: generate_input::<aoc_2021_day_18::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 19,
+        generate: generate_input::<aoc_2021_day_19::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 20,
+        generate: generate_input::<aoc_2021_day_20::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 21,
+        generate: generate_input::<aoc_2021_day_21::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 22,
+        generate: generate_input::<aoc_2021_day_22::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 24,
+        generate: generate_input::<aoc_2021_day_24::Solver>,
+    },
+    Generator {
+        year: 2021,
+        day: 25,
+        generate: generate_input::<aoc_2021_day_25::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 1,
+        generate: generate_input::<aoc_2022_day_1::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 2,
+        generate: generate_input::<aoc_2022_day_2::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 3,
+        generate: generate_input::<aoc_2022_day_3::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 4,
+        generate: generate_input::<aoc_2022_day_4::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 5,
+        generate: generate_input::<aoc_2022_day_5::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 6,
+        generate: generate_input::<aoc_2022_day_6::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 7,
+        generate: generate_input::<aoc_2022_day_7::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 8,
+        generate: generate_input::<aoc_2022_day_8::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 9,
+        generate: generate_input::<aoc_2022_day_9::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 10,
+        generate: generate_input::<aoc_2022_day_10::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 11,
+        generate: generate_input::<aoc_2022_day_11::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 12,
+        generate: generate_input::<aoc_2022_day_12::Solver>,
+    },
+    Generator {
+        year: 2022,
+        day: 13,
+        generate: generate_input::<aoc_2022_day_13::Solver>,
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::registry::DAYS;
+
+    #[test]
+    fn test_generators() {
+        // solvers are sized for the workers' stacks, not the test thread's
+        let check = || {
+            for generator in GENERATORS {
+                let (year, day) = (generator.year, generator.day);
+                let solver = DAYS
+                    .iter()
+                    .find(|d| (d.year, d.day) == (year, day))
+                    .unwrap_or_else(|| panic!("{year} day {day} is not registered"));
+                for seed in 0..3 {
+                    let input = (generator.generate)(seed, 6);
+                    assert_eq!(input, (generator.generate)(seed, 6));
+                    if let Err(e) = solver.solve_timed(&input) {
+                        panic!("{year} day {day} seed {seed}: {e}\n{input}");
+                    }
+                }
+            }
+        };
+        std::thread::Builder::new()
+            .stack_size(crate::pool::STACK_SIZE)
+            .spawn(check)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
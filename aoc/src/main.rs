@@ -0,0 +1,543 @@
+use std::{io::IsTerminal, path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc_common::input::{InputError, Source};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use tracing::Level;
+
+mod bench;
+#[cfg(test)]
+mod fake_server;
+mod fetch;
+mod generate;
+mod output;
+mod pool;
+mod registry;
+mod scaffold;
+mod submit;
+mod verify;
+mod visual;
+
+use registry::{Day, DAYS};
+use verify::Outcome;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Trace what the solvers do on stderr: `-v` for a summary of each
+    /// stage, `-vv` for every step
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of a single day, a whole year or every day
+    Run(RunArgs),
+    /// Check the solvers against the answers recorded in each day's
+    /// `answers.toml`, exiting non-zero on any mismatch
+    Verify(Selection),
+    /// Time parse, part 1 and part 2 of each day over several runs; build
+    /// with `--release` for meaningful numbers
+    Bench(BenchArgs),
+    /// Download the input of a day into the per-user cache, unless it is
+    /// there already
+    Fetch(FetchArgs),
+    /// Solve a day and send the answer of one part, unless an earlier guess
+    /// or a cooldown rules it out
+    Submit(SubmitArgs),
+    /// Create the crate of a new day from a template, with sample tests, and
+    /// register it in the workspace and the runner
+    New(NewArgs),
+    /// Record the frames of a simulation and play them in the terminal or
+    /// save them as images; lists the days to watch without a day
+    Visualise(VisualiseArgs),
+    /// Make up a valid input of any size for a day, to pipe into
+    /// `aoc run <year> <day> -i -`
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Puzzle year, e.g. 2021
+    year: Option<u16>,
+    /// Puzzle day, e.g. 15
+    day: Option<u8>,
+    /// Run every registered day
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Input file, `-` for stdin, or a directory holding `<year>/day-<n>`
+    /// inputs; defaults to the inputs checked into this repository
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Output format; json and csv give one record per part with its answer,
+    /// timings and status
+    #[arg(long, value_enum, default_value_t)]
+    format: output::Format,
+    /// Days solved at the same time, each on its own thread
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+    /// Give up on a day when its parse or one of its parts runs longer than
+    /// this many seconds
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Timed runs per day
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Compare the medians with a baseline saved by an earlier run
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+    /// Save the medians of this run as a baseline, replacing the entries of
+    /// the benchmarked days if FILE already exists
+    #[arg(long, value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Puzzle year, e.g. 2021
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    /// Puzzle day, e.g. 15
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Puzzle year, e.g. 2021
+    year: u16,
+    /// Puzzle day, e.g. 15
+    day: u8,
+    /// Part whose answer to send
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Input file, `-` for stdin, or a directory holding `<year>/day-<n>`
+    /// inputs; defaults to the inputs checked into this repository
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Puzzle year, e.g. 2021
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    /// Puzzle day, e.g. 15
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Answer to part 1 of the sample, expected by the generated test
+    #[arg(long, value_name = "ANSWER")]
+    part1: Option<String>,
+    /// Answer to part 2 of the sample, expected by the generated test
+    #[arg(long, value_name = "ANSWER")]
+    part2: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Puzzle year, e.g. 2021
+    year: u16,
+    /// Puzzle day, e.g. 22
+    day: u8,
+    /// Seed of the input, the same seed giving the same input; drawn from
+    /// the clock and reported on stderr when left out
+    #[arg(long)]
+    seed: Option<u64>,
+    /// How big the input is, in the unit of the day (lines, cuboids,
+    /// monkeys...)
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+    /// File to write the input to instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct VisualiseArgs {
+    /// Puzzle year, e.g. 2021
+    #[arg(requires = "day")]
+    year: Option<u16>,
+    /// Puzzle day, e.g. 25
+    day: Option<u8>,
+    /// Where the frames go
+    #[arg(long, value_enum, default_value_t)]
+    to: visual::Target,
+    /// The GIF file, or the directory of PNG files, to write
+    #[arg(short, long, value_name = "PATH")]
+    out: Option<PathBuf>,
+    /// Frames per second when playing or in a GIF
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=100))]
+    fps: u32,
+    /// Pixels per cell side in images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+    /// Keep one frame in this many, for long simulations
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+    /// Stop recording after this many frames
+    #[arg(long, value_name = "N", default_value_t = 1000)]
+    limit: usize,
+    /// Input file, `-` for stdin, or a directory holding `<year>/day-<n>`
+    /// inputs; defaults to the inputs checked into this repository
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
+}
+
+/// Root of this repository.
+fn repo_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// The inputs checked into this repository, next to each day crate.
+fn repo_inputs() -> Source {
+    Source::Dir(repo_root())
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<&'static Day>, String> {
+        if !self.all && self.year.is_none() {
+            return Err("specify a year (and optionally a day) or --all".to_string());
+        }
+        let days = DAYS
+            .iter()
+            .filter(|d| self.year.is_none_or(|year| d.year == year))
+            .filter(|d| self.day.is_none_or(|day| d.day == day))
+            .collect::<Vec<_>>();
+        if days.is_empty() {
+            return Err(match (self.year, self.day) {
+                (Some(year), Some(day)) => format!("no solver for {year} day {day}"),
+                (Some(year), None) => format!("no solver for {year}"),
+                _ => "no solver registered".to_string(),
+            });
+        }
+        Ok(days)
+    }
+
+    fn source(&self) -> Source {
+        match &self.input {
+            Some(path) => Source::from_arg(path),
+            None => repo_inputs(),
+        }
+    }
+
+    /// The selected days along with where to read their inputs.
+    fn days_and_source(&self) -> Result<(Vec<&'static Day>, Source), String> {
+        let days = self.days()?;
+        let source = self.source();
+        if days.len() > 1 && !source.is_shared() {
+            return Err("a single input file or stdin can only feed one day".to_string());
+        }
+        Ok((days, source))
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let (days, source) = args.selection.days_and_source()?;
+    let timeout = args.timeout.map(Duration::from_secs);
+    let mut emitter = output::Emitter::new(args.format);
+    let mut tally = pool::Tally::default();
+    let mut emitted = Ok(());
+    pool::run(
+        &days,
+        &source,
+        args.jobs as usize,
+        timeout,
+        |day, outcome| {
+            tally.add(&outcome);
+            if args.format != output::Format::Text {
+                let records = output::records(day, &outcome);
+                if emitted.is_ok() {
+                    emitted = emitter.emit(&mut std::io::stdout(), &records);
+                }
+                return;
+            }
+            println!("--- {} day {} ---", day.year, day.day);
+            match outcome {
+                pool::Outcome::Solved(..) => print!("{outcome}"),
+                _ => eprintln!("{outcome}"),
+            }
+        },
+    );
+    emitted?;
+    if args.format == output::Format::Text {
+        println!("{tally}");
+    } else {
+        eprintln!("{tally}");
+    }
+    if !tally.all_solved() {
+        return Err("some days could not be run".to_string());
+    }
+    Ok(())
+}
+
+fn verify(selection: &Selection) -> Result<(), String> {
+    let days = selection.days()?;
+    let Source::Dir(root) = selection.source() else {
+        return Err("verify needs a directory of day crates holding answers files".to_string());
+    };
+    let (mut passed, mut mismatched, mut failed, mut unchecked) = (0, 0, 0, 0);
+    for day in days {
+        println!("--- {} day {} ---", day.year, day.day);
+        let Some(checks) = verify::verify_day(day, &verify::day_dir(&root, day)) else {
+            println!("no answers recorded");
+            unchecked += 1;
+            continue;
+        };
+        for check in checks {
+            println!("{check}");
+            match check.outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Mismatch { .. } => mismatched += 1,
+                Outcome::Fail(_) => failed += 1,
+            }
+        }
+    }
+    println!(
+        "{passed} passed, {mismatched} mismatched, {failed} failed, {unchecked} days without answers"
+    );
+    if mismatched + failed > 0 {
+        return Err("verification failed".to_string());
+    }
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let (days, source) = args.selection.days_and_source()?;
+    let baseline = args.baseline.as_deref().map(bench::load).transpose()?;
+    let mut saved = match &args.save_baseline {
+        Some(path) if path.exists() => bench::load(path)?,
+        _ => bench::Baseline::new(),
+    };
+    println!("{}", bench::HEADER);
+    let mut failed = false;
+    for day in days {
+        let input = match source.read(day.year, day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{e}");
+                failed = true;
+                continue;
+            }
+        };
+        let stats = match bench::bench_day(day, &input, args.iterations as usize) {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprintln!("{} day {}: {e}", day.year, day.day);
+                failed = true;
+                continue;
+            }
+        };
+        let previous = baseline.as_ref().and_then(|b| b.get(&bench::key(day)));
+        print!("{}", bench::rows(day, &stats, previous));
+        saved.insert(bench::key(day), bench::Medians::of(&stats));
+    }
+    if let Some(path) = &args.save_baseline {
+        bench::save(path, &saved)?;
+        println!("baseline saved to {}", path.display());
+    }
+    if failed {
+        return Err("some days could not be run".to_string());
+    }
+    Ok(())
+}
+
+/// A client of the puzzle website set up from the config file and the
+/// environment.
+fn fetcher() -> Result<fetch::Fetcher, String> {
+    let config = match fetch::Config::path() {
+        Some(path) => fetch::Config::load(&path)?,
+        None => fetch::Config::default(),
+    };
+    fetch::Fetcher::from_config(config)
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+    let fetcher = fetcher()?;
+    match fetcher.fetch(args.year, args.day)? {
+        fetch::Fetched::Cached(path) => println!("already cached at {}", path.display()),
+        fetch::Fetched::Downloaded(path) => println!("saved to {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let day = DAYS
+        .iter()
+        .find(|d| d.year == args.year && d.day == args.day)
+        .ok_or_else(|| format!("no solver for {} day {}", args.year, args.day))?;
+    let source = args
+        .input
+        .as_ref()
+        .map_or_else(repo_inputs, Source::from_arg);
+    let input = source.read(day.year, day.day).map_err(|e| e.to_string())?;
+    let (answers, _) = day
+        .solve_timed(&input)
+        .map_err(|e| InputError::from(e).to_string())?;
+    let answer = if args.part == 1 {
+        answers.part1
+    } else {
+        answers.part2
+    };
+    if answer.contains('\n') {
+        return Err(format!(
+            "the answer of part {} is drawn on several lines, read it off and send it by hand:\n{answer}",
+            args.part
+        ));
+    }
+    let (verdict, wait) = submit::submit(&fetcher()?, day.year, day.day, args.part, &answer)?;
+    let wait = wait.map_or(String::new(), |wait| {
+        format!(", wait {} before the next answer", submit::human(wait))
+    });
+    println!(
+        "{} day {} part {}: {answer} is {verdict}{wait}",
+        day.year, day.day, args.part
+    );
+    if verdict != submit::Verdict::Correct {
+        return Err("answer rejected".to_string());
+    }
+    Ok(())
+}
+
+fn new(args: &NewArgs) -> Result<(), String> {
+    let new = scaffold::NewDay {
+        year: args.year,
+        day: args.day,
+        part1: args.part1.clone(),
+        part2: args.part2.clone(),
+    };
+    // an input fetched already saves a copy by hand
+    let cached = fetcher()
+        .ok()
+        .map(|f| f.cache_path(args.year, args.day))
+        .filter(|path| path.is_file());
+    let root = repo_root();
+    for path in scaffold::create(&root, &new, cached.as_deref())? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    if cached.is_none() {
+        println!(
+            "no input cached yet, `aoc fetch {} {}` then copy it to src/input",
+            args.year, args.day
+        );
+    }
+    println!("paste the sample into src/sample, then solve away");
+    Ok(())
+}
+
+fn visualise(args: &VisualiseArgs) -> Result<(), String> {
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        for v in visual::VISUALS {
+            println!("{} day {}: {}", v.year, v.day, v.about);
+        }
+        return Ok(());
+    };
+    let visual = visual::VISUALS
+        .iter()
+        .find(|v| v.year == year && v.day == day)
+        .ok_or_else(|| format!("nothing to visualise for {year} day {day}"))?;
+    let source = args
+        .input
+        .as_ref()
+        .map_or_else(repo_inputs, Source::from_arg);
+    let input = source.read(year, day).map_err(|e| e.to_string())?;
+    let mut recorder = aoc_common::frames::Recorder::new(args.every as usize, args.limit);
+    (visual.record)(&input, &mut recorder).map_err(|e| InputError::from(e).to_string())?;
+    let offered = recorder.offered();
+    let frames = recorder.into_frames();
+    let note = visual::export(
+        &frames,
+        args.to,
+        args.out.as_deref(),
+        args.fps,
+        args.scale as usize,
+    )?;
+    if !note.is_empty() {
+        println!("{note}");
+    }
+    if frames.len() * (args.every as usize) < offered {
+        eprintln!(
+            "kept {} of {offered} frames, raise --limit or --every to see the end",
+            frames.len()
+        );
+    }
+    Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let generator = generate::GENERATORS
+        .iter()
+        .find(|g| g.year == year && g.day == day)
+        .ok_or_else(|| format!("no generator for {year} day {day}"))?;
+    let seed = args.seed.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+        let seed = now.map_or(0, |d| d.as_nanos() as u64);
+        eprintln!("seed {seed}");
+        seed
+    });
+    let input = (generator.generate)(seed, args.size);
+    match &args.out {
+        Some(path) => {
+            std::fs::write(path, input + "\n").map_err(|e| format!("write {}: {e}", path.display()))
+        }
+        None => {
+            println!("{input}");
+            Ok(())
+        }
+    }
+}
+
+/// Send the traces at the level `verbose` asks for to stderr, leaving stdout
+/// to the answers. Only warnings without `-v`.
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => Level::WARN,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+    let result = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Verify(selection) => verify(&selection),
+        Command::Bench(args) => bench(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::New(args) => new(&args),
+        Command::Visualise(args) => visualise(&args),
+        Command::Generate(args) => generate(&args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
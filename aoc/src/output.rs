@@ -0,0 +1,184 @@
+use std::io::Write;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{pool::Outcome, registry::Day};
+
+/// How `aoc run` prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Answers for people to read
+    #[default]
+    Text,
+    /// One JSON object per line and part
+    Json,
+    /// A header, then one row per part
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    /// The input could not be read or parsed, see the error.
+    Error,
+    Panicked,
+    Timeout,
+}
+
+/// The result of one part of a day. Durations are in nanoseconds, the parse
+/// one is shared by both parts of a day and, where `parse_solves` is set,
+/// also covers solving.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub parse_solves: Option<bool>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// The records of both parts of a run of `day`. A day that did not finish
+/// has both parts marked with its status, the error telling which stage
+/// failed.
+pub fn records(day: &Day, outcome: &Outcome) -> [Record; 2] {
+    let nanos = |d: std::time::Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+    [1, 2].map(|part| {
+        let record = Record {
+            year: day.year,
+            day: day.day,
+            part,
+            status: Status::Ok,
+            answer: None,
+            parse_ns: None,
+            parse_solves: None,
+            solve_ns: None,
+            error: None,
+        };
+        let status = match outcome {
+            Outcome::Solved(answers, timings) => {
+                let (answer, solve) = if part == 1 {
+                    (&answers.part1, timings.part1)
+                } else {
+                    (&answers.part2, timings.part2)
+                };
+                return Record {
+                    answer: Some(answer.clone()),
+                    parse_ns: Some(nanos(timings.parse)),
+                    parse_solves: Some(timings.parse_solves),
+                    solve_ns: Some(nanos(solve)),
+                    ..record
+                };
+            }
+            Outcome::Failed(_) => Status::Error,
+            Outcome::Panicked { .. } => Status::Panicked,
+            Outcome::TimedOut { .. } => Status::Timeout,
+        };
+        Record {
+            status,
+            error: Some(outcome.to_string()),
+            ..record
+        }
+    })
+}
+
+/// Writes records in a machine-readable [`Format`], the CSV header once.
+pub struct Emitter {
+    format: Format,
+    header_done: bool,
+}
+
+impl Emitter {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            header_done: false,
+        }
+    }
+
+    pub fn emit(&mut self, out: &mut impl Write, records: &[Record]) -> Result<(), String> {
+        match self.format {
+            Format::Text => unreachable!("text output is not made of records"),
+            Format::Json => records.iter().try_for_each(|record| {
+                let line = serde_json::to_string(record).map_err(|e| e.to_string())?;
+                writeln!(out, "{line}").map_err(|e| e.to_string())
+            }),
+            Format::Csv => {
+                let mut csv = csv::WriterBuilder::new()
+                    .has_headers(!self.header_done)
+                    .from_writer(out);
+                for record in records {
+                    csv.serialize(record).map_err(|e| e.to_string())?;
+                }
+                self.header_done = true;
+                csv.flush().map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::time::Duration;
+
+    use aoc_common::solution::{Answers, Stage, Timings};
+
+    use crate::registry::DAYS;
+
+    fn emit(format: Format, batches: &[&[Record]]) -> String {
+        let mut emitter = Emitter::new(format);
+        let mut out = vec![];
+        for records in batches {
+            emitter.emit(&mut out, records).unwrap();
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_records() {
+        let answers = Answers {
+            part1: "7".to_string(),
+            part2: "#.\n.#".to_string(),
+        };
+        let timings = Timings {
+            parse: Duration::from_nanos(10),
+            part1: Duration::from_micros(2),
+            part2: Duration::from_millis(3),
+            parse_solves: false,
+        };
+        let ok = records(&DAYS[0], &Outcome::Solved(answers, timings));
+        let error = "malformed input: line 1, column 1: expected a number".to_string();
+        let failed = records(&DAYS[1], &Outcome::Failed(error));
+        let timeout = Outcome::TimedOut {
+            stage: Stage::Part2,
+            after: Duration::from_secs(30),
+        };
+
+        assert_eq!(
+            emit(Format::Json, &[&ok[..1], &failed[1..]]),
+            "{\"year\":2020,\"day\":1,\"part\":1,\"status\":\"ok\",\"answer\":\"7\",\
+             \"parse_ns\":10,\"parse_solves\":false,\"solve_ns\":2000,\"error\":null}\n\
+             {\"year\":2021,\"day\":1,\"part\":2,\"status\":\"error\",\"answer\":null,\
+             \"parse_ns\":null,\"parse_solves\":null,\"solve_ns\":null,\"error\":\"malformed input: line 1, \
+             column 1: expected a number\"}\n"
+        );
+        assert_eq!(
+            emit(
+                Format::Csv,
+                &[&ok, &failed[..1], &records(&DAYS[2], &timeout)[..1]]
+            ),
+            "year,day,part,status,answer,parse_ns,parse_solves,solve_ns,error\n\
+             2020,1,1,ok,7,10,false,2000,\n\
+             2020,1,2,ok,\"#.\n.#\",10,false,3000000,\n\
+             2021,1,1,error,,,,,\"malformed input: line 1, column 1: expected a number\"\n\
+             2021,2,1,timeout,,,,,timed out in part 2 after 30s\n"
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    sync::{
+        mpsc::{self, Sender},
+        Once,
+    },
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    input::{InputError, Source},
+    solution::{Answers, Stage, Timings},
+};
+
+use crate::registry::Day;
+
+/// How a day ended in a [`run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answers, Timings),
+    /// The input could not be read or parsed.
+    Failed(String),
+    Panicked {
+        stage: Stage,
+        message: String,
+    },
+    TimedOut {
+        stage: Stage,
+        after: Duration,
+    },
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answers, _) => write!(f, "{answers}"),
+            Self::Failed(e) => write!(f, "{e}"),
+            Self::Panicked { stage, message } => write!(f, "panicked in {stage}: {message}"),
+            Self::TimedOut { stage, after } => write!(f, "timed out in {stage} after {after:?}"),
+        }
+    }
+}
+
+/// Outcomes of a run by kind, for its summary.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Tally {
+    pub solved: usize,
+    pub failed: usize,
+    pub panicked: usize,
+    pub timed_out: usize,
+}
+
+impl Tally {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Solved(..) => self.solved += 1,
+            Outcome::Failed(_) => self.failed += 1,
+            Outcome::Panicked { .. } => self.panicked += 1,
+            Outcome::TimedOut { .. } => self.timed_out += 1,
+        }
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.failed + self.panicked + self.timed_out == 0
+    }
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} solved, {} failed, {} panicked, {} timed out",
+            self.solved, self.failed, self.panicked, self.timed_out
+        )
+    }
+}
+
+enum Event {
+    Started(Stage),
+    Done(Result<(Answers, Timings), String>),
+    Panicked(String),
+}
+
+thread_local! {
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+    // message of the last panic of a worker thread, left by the panic hook
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keep panics of worker threads off stderr, they are reported with their
+/// day instead. Other threads panic as usual.
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if WORKER.with(Cell::get) {
+                PANIC.with(|p| *p.borrow_mut() = Some(panic_message(info)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload");
+    match info.location() {
+        Some(at) => format!("{message} ({}:{})", at.file(), at.line()),
+        None => message.to_string(),
+    }
+}
+
+// some solvers recurse deeply, spawned threads only get 2 MiB by default
+pub const STACK_SIZE: usize = 64 << 20;
+
+fn spawn(idx: usize, day: &'static Day, source: Source, events: Sender<(usize, Event)>) {
+    let thread = std::thread::Builder::new()
+        .name(format!("{} day {}", day.year, day.day))
+        .stack_size(STACK_SIZE);
+    let spawned = thread.spawn(move || {
+        WORKER.with(|w| w.set(true));
+        let _day = day.span().entered();
+        let stages = events.clone();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let input = source.read(day.year, day.day).map_err(|e| e.to_string())?;
+            let mut on_stage = |stage| {
+                let _ = stages.send((idx, Event::Started(stage)));
+            };
+            (day.solve)(&input, &mut on_stage).map_err(|e| InputError::from(e).to_string())
+        }));
+        let event = match result {
+            Ok(result) => Event::Done(result),
+            Err(_) => Event::Panicked(PANIC.with(|p| p.borrow_mut().take()).unwrap_or_default()),
+        };
+        // the receiver is gone if the day timed out
+        let _ = events.send((idx, event));
+    });
+    spawned.expect("spawn a solver thread");
+}
+
+/// Solve `days` on up to `jobs` threads, reading their inputs from
+/// `source`. A panic only ends its own day, and with a `timeout` a stage
+/// running longer is given up on. Threads cannot be killed, so a timed out
+/// day keeps its thread busy in the background until the process exits, but
+/// no longer holds up the other days. `report` gets each outcome in the
+/// order of `days`, as soon as the days before it are done.
+pub fn run(
+    days: &[&'static Day],
+    source: &Source,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(&'static Day, Outcome),
+) {
+    let (events, received) = mpsc::channel();
+    let mut outcomes = vec![None; days.len()];
+    // days started and not done yet, with their current stage and its start
+    let mut running = HashMap::<usize, (Stage, Instant)>::new();
+    let (mut next_start, mut next_report) = (0, 0);
+
+    install_panic_hook();
+    while next_report < days.len() {
+        while running.len() < jobs.max(1) && next_start < days.len() {
+            spawn(next_start, days[next_start], source.clone(), events.clone());
+            running.insert(next_start, (Stage::Parse, Instant::now()));
+            next_start += 1;
+        }
+
+        let deadline = timeout.and_then(|t| running.values().map(|(_, since)| *since + t).min());
+        let event = match deadline {
+            Some(deadline) => received
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .ok(),
+            // `events` is still held here, so this never fails
+            None => received.recv().ok(),
+        };
+        if let Some((idx, event)) = event {
+            if let Some(&(stage, _)) = running.get(&idx) {
+                match event {
+                    Event::Started(stage) => {
+                        running.insert(idx, (stage, Instant::now()));
+                    }
+                    Event::Done(result) => {
+                        running.remove(&idx);
+                        outcomes[idx] = Some(match result {
+                            Ok((answers, timings)) => Outcome::Solved(answers, timings),
+                            Err(e) => Outcome::Failed(e),
+                        });
+                    }
+                    Event::Panicked(message) => {
+                        running.remove(&idx);
+                        outcomes[idx] = Some(Outcome::Panicked { stage, message });
+                    }
+                }
+            }
+        }
+        if let Some(after) = timeout {
+            let now = Instant::now();
+            running.retain(|&idx, &mut (stage, since)| {
+                let expired = now >= since + after;
+                if expired {
+                    outcomes[idx] = Some(Outcome::TimedOut { stage, after });
+                }
+                !expired
+            });
+        }
+
+        while let Some(outcome) = outcomes.get_mut(next_report).and_then(Option::take) {
+            report(days[next_report], outcome);
+            next_report += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use aoc_common::{
+        parse::ParseError,
+        solution::{solve_observed, Solution},
+    };
+
+    /// Sleeps for as many milliseconds as its input says in part 1, and
+    /// panics in part 2 on a negative input.
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        type Input = i64;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<i64, ParseError> {
+            aoc_common::parse::number(input, input)
+        }
+
+        fn part1(ms: &i64) -> i64 {
+            std::thread::sleep(Duration::from_millis(ms.unsigned_abs()));
+            *ms
+        }
+
+        fn part2(ms: &i64) -> i64 {
+            assert!(*ms >= 0, "negative input");
+            ms * 2
+        }
+    }
+
+    const SLEEPY: Day = Day {
+        year: 2000,
+        day: 1,
+        solve: solve_observed::<Sleepy>,
+    };
+
+    fn outcomes(inputs: &[&str], jobs: usize, timeout: Option<Duration>) -> Vec<Outcome> {
+        let dir = std::env::temp_dir().join(format!("aoc-pool-{}", std::process::id()));
+        let mut results = vec![];
+        for input in inputs {
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(format!("{jobs}-{input}"));
+            std::fs::write(&path, input).unwrap();
+            let source = Source::File(path);
+            run(&[&SLEEPY], &source, jobs, timeout, |_, o| results.push(o));
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+        results
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let outcomes = outcomes(
+            &["5", "x", "-5", "2000"],
+            2,
+            Some(Duration::from_millis(200)),
+        );
+        assert!(matches!(&outcomes[0], Outcome::Solved(a, _) if a.part2 == "10"));
+        assert!(matches!(&outcomes[1], Outcome::Failed(e) if e.contains("expected a number")));
+        assert!(matches!(
+            &outcomes[2],
+            Outcome::Panicked { stage: Stage::Part2, message } if message.starts_with("negative input (")
+        ));
+        assert_eq!(
+            outcomes[3],
+            Outcome::TimedOut {
+                stage: Stage::Part1,
+                after: Duration::from_millis(200)
+            }
+        );
+        let mut tally = Tally::default();
+        outcomes.iter().for_each(|o| tally.add(o));
+        assert_eq!(
+            tally.to_string(),
+            "1 solved, 1 failed, 1 panicked, 1 timed out"
+        );
+    }
+
+    #[test]
+    fn test_parallel_in_order() {
+        let dir = std::env::temp_dir().join(format!("aoc-pool-order-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2000")).unwrap();
+        static DAYS: [Day; 3] = [
+            Day { day: 1, ..SLEEPY },
+            Day { day: 2, ..SLEEPY },
+            Day { day: 3, ..SLEEPY },
+        ];
+        for (day, ms) in [(1, "300"), (2, "10"), (3, "10")] {
+            std::fs::write(dir.join(format!("2000/day-{day}")), ms).unwrap();
+        }
+        let days = DAYS.iter().collect::<Vec<_>>();
+        let start = Instant::now();
+        let mut order = vec![];
+        run(&days, &Source::Dir(dir.clone()), 3, None, |day, outcome| {
+            assert!(matches!(outcome, Outcome::Solved(..)));
+            order.push(day.day);
+        });
+        // all three at once, reported in order despite day 1 finishing last
+        assert!(start.elapsed() < Duration::from_millis(600));
+        assert_eq!(order, [1, 2, 3]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+use aoc_common::{
+    parse::ParseError,
+    solution::{solve_observed, Answers, Stage, Timings},
+};
+use tracing::{info_span, Span};
+
+/// A type erased [`solve_observed`].
+pub type SolveFn = fn(&str, &mut dyn FnMut(Stage)) -> Result<(Answers, Timings), ParseError>;
+
+/// A single puzzle solver reachable from the runner.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: SolveFn,
+}
+
+impl Day {
+    /// Parse `input` and answer both parts, timing each stage.
+    pub fn solve_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError> {
+        let _day = self.span().entered();
+        (self.solve)(input, &mut |_| {})
+    }
+
+    /// What the traces of a solve of this day are nested in.
+    pub fn span(&self) -> Span {
+        info_span!("day", year = self.year, day = self.day)
+    }
+}
+
+/// Every solved day, ordered by year then day.
+pub static DAYS: &[Day] = &[
+    Day {
+        year: 2020,
+        day: 1,
+        solve: solve_observed::<aoc_2020_day_1::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 1,
+        solve: solve_observed::<aoc_2021_day_1::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 2,
+        solve: solve_observed::<aoc_2021_day_2::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 3,
+        solve: solve_observed::<aoc_2021_day_3::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 4,
+        solve: solve_observed::<aoc_2021_day_4::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 5,
+        solve: solve_observed::<aoc_2021_day_5::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 6,
+        solve: solve_observed::<aoc_2021_day_6::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 7,
+        solve: solve_observed::<aoc_2021_day_7::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 8,
+        solve: solve_observed::<aoc_2021_day_8::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 9,
+        solve: solve_observed::<aoc_2021_day_9::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 10,
+        solve: solve_observed::<aoc_2021_day_10::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 11,
+        solve: solve_observed::<aoc_2021_day_11::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 12,
+        solve: solve_observed::<aoc_2021_day_12::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 13,
+        solve: solve_observed::<aoc_2021_day_13::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 14,
+        solve: solve_observed::<aoc_2021_day_14::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 15,
+        solve: solve_observed::<aoc_2021_day_15::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 16,
+        solve: solve_observed::<aoc_2021_day_16::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 17,
+        solve: solve_observed::<aoc_2021_day_17::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 18,
+        solve: solve_observed::<aoc_2021_day_18::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 19,
+        solve: solve_observed::<aoc_2021_day_19::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 20,
+        solve: solve_observed::<aoc_2021_day_20::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 21,
+        solve: solve_observed::<aoc_2021_day_21::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 22,
+        solve: solve_observed::<aoc_2021_day_22::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 24,
+        solve: solve_observed::<aoc_2021_day_24::Solver>,
+    },
+    Day {
+        year: 2021,
+        day: 25,
+        solve: solve_observed::<aoc_2021_day_25::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 1,
+        solve: solve_observed::<aoc_2022_day_1::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 2,
+        solve: solve_observed::<aoc_2022_day_2::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 3,
+        solve: solve_observed::<aoc_2022_day_3::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 4,
+        solve: solve_observed::<aoc_2022_day_4::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 5,
+        solve: solve_observed::<aoc_2022_day_5::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 6,
+        solve: solve_observed::<aoc_2022_day_6::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 7,
+        solve: solve_observed::<aoc_2022_day_7::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 8,
+        solve: solve_observed::<aoc_2022_day_8::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 9,
+        solve: solve_observed::<aoc_2022_day_9::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 10,
+        solve: solve_observed::<aoc_2022_day_10::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 11,
+        solve: solve_observed::<aoc_2022_day_11::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 12,
+        solve: solve_observed::<aoc_2022_day_12::Solver>,
+    },
+    Day {
+        year: 2022,
+        day: 13,
+        solve: solve_observed::<aoc_2022_day_13::Solver>,
+    },
+];
@@ -0,0 +1,325 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+/// A day crate to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+    /// Answers to the sample, turned into the expected values of the
+    /// generated tests.
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl NewDay {
+    fn name(&self) -> String {
+        format!("aoc-{}-day-{}", self.year, self.day)
+    }
+
+    fn dir(&self) -> String {
+        format!("{}/day-{}", self.year, self.day)
+    }
+
+    fn manifest(&self) -> String {
+        format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = {{ path = "../../common" }}
+"#,
+            self.name()
+        )
+    }
+
+    fn lib(&self) -> String {
+        let mut tests = String::new();
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            let (ignore, actual, expected) = match answer {
+                Some(a) if a.parse::<i64>().is_ok() => ("", "", a.clone()),
+                Some(a) => ("", ".to_string()", format!("{a:?}")),
+                None => (
+                    "\n    #[ignore = \"sample answer not filled in yet\"]",
+                    "",
+                    "0".to_string(),
+                ),
+            };
+            write!(
+                tests,
+                r#"
+    #[test]{ignore}
+    fn test_part{part}_sample() {{
+        let input = Solver::parse(SAMPLE).unwrap();
+        assert_eq!(Solver::part{part}(&input){actual}, {expected});
+    }}"#
+            )
+            .unwrap();
+        }
+        format!(
+            r#"use aoc_common::{{parse::ParseError, solution::Solution}};
+
+pub struct Solver;
+
+impl Solution for Solver {{
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> usize {{
+        todo!("part 1 of {year} day {day}")
+    }}
+
+    fn part2(_input: &Self::Input) -> usize {{
+        todo!("part 2 of {year} day {day}")
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+    static SAMPLE: &str = include_str!("sample");
+{tests}
+}}
+"#,
+            year = self.year,
+            day = self.day,
+            tests = tests.trim_start_matches('\n')
+        )
+    }
+
+    fn main(&self) -> String {
+        format!(
+            "fn main() -> std::process::ExitCode {{\n    \
+             aoc_common::solution::run::<{}::Solver>(env!(\"CARGO_MANIFEST_DIR\"))\n}}\n",
+            self.name().replace('-', "_")
+        )
+    }
+
+    fn answers(&self) -> String {
+        let mut s = String::from("[sample]\n");
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            match answer {
+                Some(a) if a.parse::<i64>().is_ok() => writeln!(s, "part{part} = {a}"),
+                Some(a) => writeln!(s, "part{part} = {a:?}"),
+                None => writeln!(s, "# part{part} ="),
+            }
+            .unwrap();
+        }
+        s.push_str("\n# [input]\n# part1 =\n# part2 =\n");
+        s
+    }
+}
+
+/// Add the `<year>/day-*` glob to the workspace members, `None` if it is
+/// there already.
+fn add_member(manifest: &str, year: u16) -> Result<Option<String>, String> {
+    let members = Regex::new(r"members = \[([^\]]*)\]").unwrap();
+    let list = members
+        .captures(manifest)
+        .ok_or("no workspace members list in Cargo.toml")?
+        .get(1)
+        .unwrap();
+    let glob = format!("\"{year}/day-*\"");
+    let mut items = list.as_str().split(", ").collect::<Vec<_>>();
+    if items.contains(&glob.as_str()) {
+        return Ok(None);
+    }
+    // day globs stay sorted after the other members
+    let at = items
+        .iter()
+        .position(|item| item.ends_with("/day-*\"") && *item > glob.as_str())
+        .unwrap_or(items.len());
+    items.insert(at, &glob);
+    let mut manifest = manifest.to_string();
+    manifest.replace_range(list.range(), &items.join(", "));
+    Ok(Some(manifest))
+}
+
+/// Insert `line` among the lines of `text` matched by `entry`, whose first
+/// two captures are the year and day, keeping them in order.
+fn insert_sorted(text: &str, entry: &Regex, year: u16, day: u8, line: &str) -> String {
+    let mut last_before = None;
+    let mut first_after = None;
+    for caps in entry.captures_iter(text) {
+        let key = (
+            caps[1].parse::<u16>().unwrap(),
+            caps[2].parse::<u8>().unwrap(),
+        );
+        let range = caps.get(0).unwrap().range();
+        if key < (year, day) {
+            last_before = Some(range.end);
+        } else if first_after.is_none() {
+            first_after = Some(range.start);
+        }
+    }
+    let mut text = text.to_string();
+    let at = first_after
+        .or(last_before)
+        .expect("callers check there is an entry");
+    text.insert_str(at, line);
+    text
+}
+
+/// Add the day crate to the dependencies of the runner.
+fn add_dependency(manifest: &str, new: &NewDay) -> Result<String, String> {
+    let dep = Regex::new(r"(?m)^aoc-(\d+)-day-(\d+) = .*\n").unwrap();
+    if !dep.is_match(manifest) {
+        return Err("no day crate among the runner dependencies".to_string());
+    }
+    let line = format!("{} = {{ path = \"../{}\" }}\n", new.name(), new.dir());
+    Ok(insert_sorted(manifest, &dep, new.year, new.day, &line))
+}
+
+/// Add the day to [`crate::registry::DAYS`], `registry` being the source of
+/// the registry module.
+fn add_to_registry(registry: &str, new: &NewDay) -> Result<String, String> {
+    let entry =
+        Regex::new(r"    Day \{\n        year: (\d+),\n        day: (\d+),\n[^}]*\},\n").unwrap();
+    if !entry.is_match(registry) {
+        return Err("no day in the runner registry".to_string());
+    }
+    let line = format!(
+        "    Day {{\n        year: {},\n        day: {},\n        solve: solve_observed::<{}::Solver>,\n    }},\n",
+        new.year,
+        new.day,
+        new.name().replace('-', "_")
+    );
+    Ok(insert_sorted(registry, &entry, new.year, new.day, &line))
+}
+
+/// Create the crate of a day under the repository `root` and register it in
+/// the workspace and the runner. `input` is copied into the crate when
+/// given, e.g. from the cache of `aoc fetch`. Returns the files written.
+pub fn create(root: &Path, new: &NewDay, input: Option<&Path>) -> Result<Vec<PathBuf>, String> {
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|e| format!("read {}: {e}", path.display()))
+    };
+    let day_dir = root.join(new.dir());
+    if day_dir.exists() {
+        return Err(format!("{} exists already", new.dir()));
+    }
+    // work out every edit first, so a failure leaves the tree untouched
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let registry = root.join("aoc/src/registry.rs");
+    let mut files = vec![];
+    if let Some(manifest) = add_member(&read(&workspace)?, new.year)? {
+        files.push((workspace, manifest));
+    }
+    files.push((runner.clone(), add_dependency(&read(&runner)?, new)?));
+    files.push((registry.clone(), add_to_registry(&read(&registry)?, new)?));
+    let src = day_dir.join("src");
+    files.push((day_dir.join("Cargo.toml"), new.manifest()));
+    files.push((day_dir.join("answers.toml"), new.answers()));
+    files.push((src.join("lib.rs"), new.lib()));
+    files.push((src.join("main.rs"), new.main()));
+    files.push((src.join("sample"), String::new()));
+    if let Some(input) = input {
+        files.push((src.join("input"), read(input)?));
+    }
+
+    std::fs::create_dir_all(&src).map_err(|e| format!("create {}: {e}", src.display()))?;
+    for (path, contents) in &files {
+        std::fs::write(path, contents).map_err(|e| format!("write {}: {e}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static WORKSPACE: &str = include_str!("../../Cargo.toml");
+    static RUNNER: &str = include_str!("../Cargo.toml");
+    static REGISTRY: &str = include_str!("registry.rs");
+
+    fn new_day(year: u16, day: u8) -> NewDay {
+        NewDay {
+            year,
+            day,
+            part1: Some("24".to_string()),
+            part2: None,
+        }
+    }
+
+    #[test]
+    fn test_register_in_order() {
+        assert_eq!(add_member(WORKSPACE, 2021), Ok(None));
+        let manifest = add_member(WORKSPACE, 2023).unwrap().unwrap();
+        assert!(manifest.contains("\"2022/day-*\", \"2023/day-*\"]"));
+        let manifest = add_member(WORKSPACE, 2019).unwrap().unwrap();
+        assert!(manifest.contains("\"common\", \"2019/day-*\", \"2020/day-*\""));
+
+        let runner = add_dependency(RUNNER, &new_day(2021, 23)).unwrap();
+        assert!(runner.contains(
+            "aoc-2021-day-22 = { path = \"../2021/day-22\" }\n\
+             aoc-2021-day-23 = { path = \"../2021/day-23\" }\n\
+             aoc-2021-day-24 = "
+        ));
+        let runner = add_dependency(RUNNER, &new_day(2023, 1)).unwrap();
+        assert!(runner.contains("aoc-2023-day-1 = { path = \"../2023/day-1\" }\n\n"));
+
+        let registry = add_to_registry(REGISTRY, &new_day(2021, 23)).unwrap();
+        assert!(registry.contains(
+            "aoc_2021_day_22::Solver>,\n    },\n    Day {\n        year: 2021,\n        \
+             day: 23,\n        solve: solve_observed::<aoc_2021_day_23::Solver>,\n    },\n    \
+             Day {\n        year: 2021,\n        day: 24,"
+        ));
+        let registry = add_to_registry(REGISTRY, &new_day(2023, 1)).unwrap();
+        assert!(registry.ends_with("aoc_2023_day_1::Solver>,\n    },\n];\n"));
+    }
+
+    #[test]
+    fn test_generated_day() {
+        let new = NewDay {
+            part2: Some("CMZ".to_string()),
+            ..new_day(2023, 5)
+        };
+        let lib = new.lib();
+        assert!(lib.contains("assert_eq!(Solver::part1(&input), 24);"));
+        assert!(lib.contains("assert_eq!(Solver::part2(&input).to_string(), \"CMZ\");"));
+        assert!(!lib.contains("#[ignore"));
+        assert!(new_day(2023, 5).lib().contains("#[ignore"));
+        assert!(new.main().contains("run::<aoc_2023_day_5::Solver>"));
+        assert_eq!(
+            new_day(2023, 5).answers(),
+            "[sample]\npart1 = 24\n# part2 =\n\n# [input]\n# part1 =\n# part2 =\n"
+        );
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        std::fs::write(root.join("aoc/Cargo.toml"), RUNNER).unwrap();
+        std::fs::write(root.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+        let input = root.join("cached-input");
+        std::fs::write(&input, "1\n2\n").unwrap();
+
+        let files = create(&root, &new_day(2023, 1), Some(&input)).unwrap();
+        assert_eq!(files.len(), 9);
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(read("2023/day-1/src/input"), "1\n2\n");
+        assert!(read("2023/day-1/Cargo.toml").contains("name = \"aoc-2023-day-1\""));
+        assert!(read("Cargo.toml").contains("\"2023/day-*\""));
+        assert!(read("aoc/src/registry.rs").contains("aoc_2023_day_1"));
+        let err = create(&root, &new_day(2023, 1), None).unwrap_err();
+        assert!(err.ends_with("exists already"), "{err}");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{Fetcher, USER_AGENT};
+
+/// What the server made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Incorrect,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Incorrect => write!(f, "incorrect"),
+        }
+    }
+}
+
+/// The server's reply to an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// The answer was judged, with how long to wait before sending another.
+    Judged(Verdict, Option<Duration>),
+    /// An answer was sent too recently, this one was not judged.
+    TooSoon(Duration),
+    /// The part is solved already or not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+/// Read the reply page of an answer. Only the message in its `<article>`
+/// matters, the rest is the site layout.
+pub fn parse_reply(html: &str) -> Reply {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let text = article
+        .captures(html)
+        .map_or(html, |c| c.get(1).unwrap().as_str());
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(text, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = text.to_lowercase();
+
+    if lower.contains("that's the right answer") {
+        Reply::Judged(Verdict::Correct, None)
+    } else if lower.contains("not the right answer") {
+        let verdict = if lower.contains("too high") {
+            Verdict::TooHigh
+        } else if lower.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        };
+        let wait = Regex::new(r"wait (one|\d+) minutes?").unwrap();
+        let minutes = wait.captures(&lower).map(|c| match &c[1] {
+            "one" => 1,
+            n => n.parse().unwrap(),
+        });
+        Reply::Judged(verdict, minutes.map(|m| Duration::from_secs(m * 60)))
+    } else if lower.contains("answer too recently") {
+        let left = Regex::new(r"you have ((?:\d+[hms] ?)+) left").unwrap();
+        let left = left.captures(&lower).map_or(Duration::from_secs(60), |c| {
+            Regex::new(r"(\d+)([hms])")
+                .unwrap()
+                .captures_iter(&c[1])
+                .map(|unit| {
+                    let n: u64 = unit[1].parse().unwrap();
+                    Duration::from_secs(match &unit[2] {
+                        "h" => n * 3600,
+                        "m" => n * 60,
+                        _ => n,
+                    })
+                })
+                .sum()
+        });
+        Reply::TooSoon(left)
+    } else if lower.contains("solving the right level") {
+        Reply::WrongLevel
+    } else {
+        Reply::Unknown(text)
+    }
+}
+
+/// An answer sent for a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time it was sent at.
+    pub at: u64,
+}
+
+/// Answers sent for one day, so a known wrong answer is never sent twice:
+///
+/// ```toml
+/// cooldown_until = 1670000060
+///
+/// [[guess]]
+/// part = 1
+/// answer = "1234"
+/// verdict = "too-high"
+/// at = 1670000000
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Log {
+    /// Unix time until which the server refuses answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_until: Option<u64>,
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>,
+}
+
+impl Log {
+    /// Where the log of a day is kept, next to the cached inputs.
+    pub fn path(fetcher: &Fetcher, year: u16, day: u8) -> PathBuf {
+        fetcher
+            .cache_dir
+            .join("submissions")
+            .join(year.to_string())
+            .join(format!("day-{day}.toml"))
+    }
+
+    /// The log at `path`, empty if nothing was sent yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("read {}: {e}", path.display())),
+        };
+        toml::from_str(&s).map_err(|e| format!("parse {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).map_err(|e| format!("create {}: {e}", dir.display()))?;
+        let s = toml::to_string(self).map_err(|e| format!("serialize guesses: {e}"))?;
+        std::fs::write(path, s).map_err(|e| format!("write {}: {e}", path.display()))
+    }
+
+    /// Why sending `answer` to `part` is pointless given the earlier guesses,
+    /// including numbers beyond one already too high or too low.
+    pub fn known(&self, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        self.guesses
+            .iter()
+            .filter(|g| g.part == part)
+            .find_map(|guess| {
+                let bound = number.zip(guess.answer.parse::<i128>().ok());
+                Some(match (guess.verdict, bound) {
+                    (Verdict::Correct, _) if guess.answer == answer => {
+                        format!("{answer} was accepted already")
+                    }
+                    (Verdict::Correct, _) => {
+                        format!("part {part} is solved already with {}", guess.answer)
+                    }
+                    (verdict, _) if guess.answer == answer => {
+                        format!("{answer} was rejected already as {verdict}")
+                    }
+                    (Verdict::TooHigh, Some((n, high))) if n >= high => {
+                        format!("{answer} is not below an earlier too high guess, {high}")
+                    }
+                    (Verdict::TooLow, Some((n, low))) if n <= low => {
+                        format!("{answer} is not above an earlier too low guess, {low}")
+                    }
+                    _ => return None,
+                })
+            })
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// `1m 5s`, `37s`.
+pub fn human(d: Duration) -> String {
+    match d.as_secs() {
+        s if s >= 60 && s % 60 == 0 => format!("{}m", s / 60),
+        s if s >= 60 => format!("{}m {}s", s / 60, s % 60),
+        s => format!("{s}s"),
+    }
+}
+
+/// Send `answer` to `part` of `year` `day` and record how it was judged,
+/// unless earlier guesses rule it out or the server still asks to wait.
+/// Returns the verdict and how long to wait before the next answer.
+pub fn submit(
+    fetcher: &Fetcher,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(Verdict, Option<Duration>), String> {
+    let path = Log::path(fetcher, year, day);
+    let mut log = Log::load(&path)?;
+    if let Some(reason) = log.known(part, answer) {
+        return Err(format!("not sent, {reason}"));
+    }
+    if let Some(left) = log
+        .cooldown_until
+        .and_then(|until| until.checked_sub(unix_now()))
+    {
+        if left > 0 {
+            let left = human(Duration::from_secs(left));
+            return Err(format!("not sent, wait {left} before the next answer"));
+        }
+    }
+    let session = fetcher.session()?;
+    std::fs::create_dir_all(&fetcher.cache_dir)
+        .map_err(|e| format!("create {}: {e}", fetcher.cache_dir.display()))?;
+    fetcher.wait_turn()?;
+
+    let url = format!(
+        "{}/{year}/day/{day}/answer",
+        fetcher.base_url.trim_end_matches('/')
+    );
+    let html = match ureq::post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+    {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| format!("read {url}: {e}"))?,
+        Err(ureq::Error::Status(code @ (400 | 401 | 403), _)) => {
+            return Err(format!("session token rejected ({code} from {url})"))
+        }
+        Err(ureq::Error::Status(code, _)) => return Err(format!("{code} from {url}")),
+        Err(e) => return Err(format!("post {url}: {e}")),
+    };
+
+    let now = unix_now();
+    match parse_reply(&html) {
+        Reply::Judged(verdict, wait) => {
+            log.guesses.push(Guess {
+                part,
+                answer: answer.to_string(),
+                verdict,
+                at: now,
+            });
+            log.cooldown_until = wait.map(|wait| now + wait.as_secs());
+            log.save(&path)?;
+            Ok((verdict, wait))
+        }
+        Reply::TooSoon(left) => {
+            log.cooldown_until = Some(now + left.as_secs());
+            log.save(&path)?;
+            Err(format!("answered too recently, wait {}", human(left)))
+        }
+        Reply::WrongLevel => Err(format!("part {part} is solved already or not unlocked yet")),
+        Reply::Unknown(text) => Err(format!("unexpected reply: {text}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::fake_server::{fetcher, serve};
+
+    static CORRECT: &str = "<main><article><p>That's the right answer!  You are \
+        <span class=\"day-success\">one gold star</span> closer to saving Christmas.</p>\
+        </article></main>";
+    static TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is \
+        too high.  If you're stuck, make sure you're using the full input data.  Please \
+        wait one minute before trying again. <a href=\"/2021/day/1\">[Return to Day 1]</a>\
+        </p></article>";
+    static TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to \
+        wait after submitting an answer before trying again.  You have 1m 5s left to \
+        wait. <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(parse_reply(CORRECT), Reply::Judged(Verdict::Correct, None));
+        assert_eq!(
+            parse_reply(TOO_HIGH),
+            Reply::Judged(Verdict::TooHigh, Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse_reply(
+                "<article><p>That's not the right answer.  Because you have guessed \
+                 incorrectly 4 times on this puzzle, please wait 5 minutes before trying \
+                 again.</p></article>"
+            ),
+            Reply::Judged(Verdict::Incorrect, Some(Duration::from_secs(300)))
+        );
+        assert_eq!(
+            parse_reply(TOO_SOON),
+            Reply::TooSoon(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_reply(
+                "<article><p>You don't seem to be solving the right level.  Did you \
+                 already complete it?</p></article>"
+            ),
+            Reply::WrongLevel
+        );
+        assert_eq!(
+            parse_reply("<p>Server <b>down</b></p>"),
+            Reply::Unknown("Server down".to_string())
+        );
+    }
+
+    #[test]
+    fn test_known_guesses() {
+        let guess = |answer: &str, verdict| Guess {
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            at: 0,
+        };
+        let mut log = Log {
+            cooldown_until: None,
+            guesses: vec![
+                guess("500", Verdict::TooHigh),
+                guess("100", Verdict::TooLow),
+            ],
+        };
+        assert_eq!(
+            log.known(1, "500").as_deref(),
+            Some("500 was rejected already as too high")
+        );
+        assert_eq!(
+            log.known(1, "600").as_deref(),
+            Some("600 is not below an earlier too high guess, 500")
+        );
+        assert!(log.known(1, "100").is_some());
+        assert!(log.known(1, "99").is_some());
+        assert_eq!(log.known(1, "300"), None);
+        assert_eq!(log.known(2, "500"), None);
+        log.guesses.push(guess("300", Verdict::Correct));
+        assert_eq!(
+            log.known(1, "301").as_deref(),
+            Some("part 1 is solved already with 300")
+        );
+        let s = toml::to_string(&log).unwrap();
+        assert_eq!(toml::from_str::<Log>(&s).unwrap(), log);
+    }
+
+    #[test]
+    fn test_submit_flow() {
+        let (base_url, seen, server) =
+            serve(vec![(200, TOO_HIGH), (200, TOO_SOON), (200, CORRECT)]);
+        let fetcher = fetcher("submit", &base_url);
+        let path = Log::path(&fetcher, 2021, 1);
+        assert_eq!(
+            submit(&fetcher, 2021, 1, 1, "500"),
+            Ok((Verdict::TooHigh, Some(Duration::from_secs(60))))
+        );
+        // refused locally, the server never sees these
+        let err = submit(&fetcher, 2021, 1, 1, "500").unwrap_err();
+        assert!(err.contains("rejected already"), "{err}");
+        let err = submit(&fetcher, 2021, 1, 1, "400").unwrap_err();
+        assert!(err.starts_with("not sent, wait"), "{err}");
+
+        // pretend the minute went by
+        let mut log = Log::load(&path).unwrap();
+        log.cooldown_until = Some(0);
+        log.save(&path).unwrap();
+        let err = submit(&fetcher, 2021, 1, 1, "400").unwrap_err();
+        assert_eq!(err, "answered too recently, wait 1m 5s");
+        let mut log = Log::load(&path).unwrap();
+        assert!(log.cooldown_until.unwrap() >= unix_now() + 60);
+        log.cooldown_until = None;
+        log.save(&path).unwrap();
+        assert_eq!(
+            submit(&fetcher, 2021, 1, 1, "400"),
+            Ok((Verdict::Correct, None))
+        );
+        let err = submit(&fetcher, 2021, 1, 1, "400").unwrap_err();
+        assert_eq!(err, "not sent, 400 was accepted already");
+
+        server.join().unwrap();
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen[0].method, "POST");
+        assert_eq!(seen[0].url, "/2021/day/1/answer");
+        assert_eq!(seen[0].cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(seen[0].body, "level=1&answer=500");
+        let log = Log::load(&path).unwrap();
+        assert_eq!(
+            log.guesses
+                .iter()
+                .map(|g| (g.answer.as_str(), g.verdict))
+                .collect::<Vec<_>>(),
+            [("500", Verdict::TooHigh), ("400", Verdict::Correct)]
+        );
+        std::fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+}
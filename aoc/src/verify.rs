@@ -0,0 +1,226 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    panic::catch_unwind,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{
+    input::{InputError, Source},
+    solution::Answers,
+};
+use serde::Deserialize;
+
+use crate::registry::Day;
+
+/// Name of the file listing the known answers of a day, next to its crate
+/// manifest.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// A known answer, numbers can be written bare in `answers.toml`.
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Answers expected from one input file, a part left out is not checked.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// One table per input file of the day, named after the file under `src/`:
+///
+/// ```toml
+/// [sample]
+/// part1 = 40
+/// part2 = 315
+///
+/// [input]
+/// part1 = 696
+/// ```
+pub type KnownAnswers = BTreeMap<String, Expected>;
+
+/// The known answers of a day crate, `None` if it has no answers file.
+pub fn load(day_dir: &Path) -> Result<Option<KnownAnswers>, String> {
+    let path = day_dir.join(ANSWERS_FILE);
+    let s = match std::fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("read {}: {e}", path.display())),
+    };
+    toml::from_str(&s)
+        .map(Some)
+        .map_err(|e| format!("parse {}: {e}", path.display()))
+}
+
+/// Result of checking one part against one input file.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The input could not be read or the solver panicked.
+    Fail(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "ok"),
+            Self::Mismatch { expected, actual } => {
+                // multi-line answers are easier to compare one above the other
+                if expected.contains('\n') || actual.contains('\n') {
+                    write!(f, "MISMATCH\nexpected:\n{expected}\nactual:\n{actual}")
+                } else {
+                    write!(f, "MISMATCH expected {expected}, got {actual}")
+                }
+            }
+            Self::Fail(reason) => write!(f, "FAIL {reason}"),
+        }
+    }
+}
+
+/// Compare the answers of a run with the expected ones, part by part.
+pub fn compare(expected: &Expected, answers: &Answers) -> Vec<(u8, Outcome)> {
+    [
+        (1, &expected.part1, &answers.part1),
+        (2, &expected.part2, &answers.part2),
+    ]
+    .into_iter()
+    .filter_map(|(part, expected, actual)| {
+        let expected = expected.as_ref()?.to_string();
+        let outcome = if expected == *actual {
+            Outcome::Pass
+        } else {
+            Outcome::Mismatch {
+                expected,
+                actual: actual.clone(),
+            }
+        };
+        Some((part, outcome))
+    })
+    .collect()
+}
+
+/// One check of a day: a part of an input file, or the whole file when it
+/// could not be run at all.
+#[derive(Debug)]
+pub struct Check {
+    pub file: String,
+    pub part: Option<u8>,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "{} part {part}: {}", self.file, self.outcome),
+            None => write!(f, "{}: {}", self.file, self.outcome),
+        }
+    }
+}
+
+/// Run `day` over every input listed in its answers file, `None` if no
+/// answers are recorded for it.
+pub fn verify_day(day: &Day, day_dir: &Path) -> Option<Vec<Check>> {
+    let fail = |file: &str, reason| Check {
+        file: file.to_string(),
+        part: None,
+        outcome: Outcome::Fail(reason),
+    };
+    let known = match load(day_dir) {
+        Ok(known) => known?,
+        Err(e) => return Some(vec![fail(ANSWERS_FILE, e)]),
+    };
+    let mut results = vec![];
+    for (file, expected) in &known {
+        let path = day_dir.join("src").join(file);
+        let answers = Source::File(path)
+            .read(day.year, day.day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                catch_unwind(|| day.solve_timed(&input))
+                    .map_err(|_| "solver panicked".to_string())?
+                    .map(|(answers, _)| answers)
+                    .map_err(|e| InputError::from(e).to_string())
+            });
+        match answers {
+            Ok(answers) => results.extend(compare(expected, &answers).into_iter().map(
+                |(part, outcome)| Check {
+                    file: file.clone(),
+                    part: Some(part),
+                    outcome,
+                },
+            )),
+            Err(e) => results.push(fail(file, e)),
+        }
+    }
+    Some(results)
+}
+
+/// Directory of a day crate under the repository root.
+pub fn day_dir(root: &Path, day: &Day) -> PathBuf {
+    root.join(day.year.to_string())
+        .join(format!("day-{}", day.day))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn answers(part1: &str, part2: &str) -> Answers {
+        Answers {
+            part1: part1.to_string(),
+            part2: part2.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_known_answers() {
+        let known: KnownAnswers =
+            toml::from_str("[sample]\npart1 = 40\npart2 = \"CMZ\"\n\n[input]\npart1 = 696\n")
+                .unwrap();
+        assert_eq!(known.len(), 2);
+        assert_eq!(known["sample"].part1, Some(Answer::Number(40)));
+        assert_eq!(known["input"].part2, None);
+        assert!(toml::from_str::<KnownAnswers>("[input]\npart3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = Expected {
+            part1: Some(Answer::Number(40)),
+            part2: None,
+        };
+        assert_eq!(
+            compare(&expected, &answers("40", "315")),
+            [(1, Outcome::Pass)]
+        );
+        assert_eq!(
+            compare(&expected, &answers("41", "315")),
+            [(
+                1,
+                Outcome::Mismatch {
+                    expected: "40".to_string(),
+                    actual: "41".to_string()
+                }
+            )]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use aoc_common::{
+    frames::{self, record_frames, Frame, Recorder},
+    parse::ParseError,
+};
+use clap::ValueEnum;
+
+/// A type erased [`record_frames`].
+pub type RecordFn = fn(&str, &mut Recorder) -> Result<(), ParseError>;
+
+/// A day whose simulation can be watched.
+pub struct Visual {
+    pub year: u16,
+    pub day: u8,
+    /// What the frames show.
+    pub about: &'static str,
+    pub record: RecordFn,
+}
+
+/// Every day with a simulation to watch, ordered by year then day.
+pub static VISUALS: &[Visual] = &[
+    Visual {
+        year: 2021,
+        day: 11,
+        about: "octopuses flashing until they all flash together",
+        record: record_frames::<aoc_2021_day_11::Solver>,
+    },
+    Visual {
+        year: 2021,
+        day: 20,
+        about: "the image growing over 50 enhancements",
+        record: record_frames::<aoc_2021_day_20::Solver>,
+    },
+    Visual {
+        year: 2021,
+        day: 25,
+        about: "sea cucumbers moving until they are stuck",
+        record: record_frames::<aoc_2021_day_25::Solver>,
+    },
+    Visual {
+        year: 2022,
+        day: 9,
+        about: "a rope of ten knots following its head",
+        record: record_frames::<aoc_2022_day_9::Solver>,
+    },
+];
+
+/// Where `aoc visualise` sends the frames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Target {
+    /// Play them in the terminal
+    #[default]
+    Terminal,
+    /// An animated GIF, written to --out
+    Gif,
+    /// One PNG per frame, written into the directory --out
+    Png,
+}
+
+/// Export `frames` to `target`, `out` being the file or directory written
+/// for images. Returns a note on what was written.
+pub fn export(
+    frames: &[Frame],
+    target: Target,
+    out: Option<&Path>,
+    fps: u32,
+    scale: usize,
+) -> Result<String, String> {
+    let out = || {
+        out.ok_or_else(|| format!("--out is needed to write a {target:?}"))
+            .map(Path::to_path_buf)
+    };
+    match target {
+        Target::Terminal => {
+            frames::play(frames, fps, &mut std::io::stdout().lock()).map_err(|e| e.to_string())?;
+            Ok(String::new())
+        }
+        Target::Gif => {
+            let path = out()?;
+            let file = std::fs::File::create(&path)
+                .map_err(|e| format!("create {}: {e}", path.display()))?;
+            frames::write_gif(frames, fps, scale, std::io::BufWriter::new(file))
+                .map_err(|e| format!("write {}: {e}", path.display()))?;
+            Ok(format!("wrote {}", path.display()))
+        }
+        Target::Png => {
+            let dir = out()?;
+            let files = frames::write_pngs(frames, scale, &dir)
+                .map_err(|e| format!("write {}: {e}", dir.display()))?;
+            Ok(format!(
+                "wrote {} frames into {}",
+                files.len(),
+                dir.display()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::registry::DAYS;
+
+    #[test]
+    fn test_visuals() {
+        for visual in VISUALS {
+            assert!(
+                DAYS.iter()
+                    .any(|d| (d.year, d.day) == (visual.year, visual.day)),
+                "{} day {} is not registered",
+                visual.year,
+                visual.day
+            );
+        }
+        let sample = include_str!("../../2021/day-11/src/sample");
+        let mut recorder = Recorder::new(1, 1000);
+        (VISUALS[0].record)(sample, &mut recorder).unwrap();
+        // the start, then the 195 steps to the first synchronised flash
+        assert_eq!(recorder.offered(), 196);
+        let last = recorder.frames().last().unwrap();
+        assert!(last.cells().iter().all(|&c| c == '#'));
+    }
+}
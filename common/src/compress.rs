@@ -0,0 +1,251 @@
+//! Coordinate compression: squeezing the few coordinates that matter on each
+//! axis down to consecutive indices, so a sparse space fits a dense grid.
+
+use std::ops::Range;
+
+use crate::interval::{Cuboid, Interval};
+
+/// The coordinates of one axis cut at some breaks into cells, each cell the
+/// integers from one break up to the next.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Axis {
+    breaks: Vec<i64>,
+}
+
+impl Axis {
+    /// The axis cut at every one of `breaks`, in any order and repeated or
+    /// not.
+    pub fn new(breaks: impl IntoIterator<Item = i64>) -> Self {
+        let mut breaks = breaks.into_iter().collect::<Vec<_>>();
+        breaks.sort_unstable();
+        breaks.dedup();
+        Self { breaks }
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.breaks.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The cell holding `n`, if any.
+    pub fn cell(&self, n: i64) -> Option<usize> {
+        let after = self.breaks.partition_point(|b| *b <= n);
+        (1..self.breaks.len()).contains(&after).then(|| after - 1)
+    }
+
+    /// The cells making up `interval`, whose ends must be breaks.
+    pub fn cells(&self, interval: &Interval) -> Range<usize> {
+        if interval.is_empty() {
+            return 0..0;
+        }
+        let index = |n| {
+            self.breaks
+                .binary_search(&n)
+                .unwrap_or_else(|_| panic!("{n} is not a break"))
+        };
+        index(interval.start)..index(interval.end)
+    }
+
+    /// The coordinates in `cell`.
+    pub fn interval(&self, cell: usize) -> Interval {
+        Interval::new(self.breaks[cell], self.breaks[cell + 1])
+    }
+
+    /// Number of coordinates in `cell`.
+    pub fn width(&self, cell: usize) -> i64 {
+        self.interval(cell).len()
+    }
+}
+
+/// A space of `N` dimensions cut along every axis, its cells numbered in
+/// row-major order for a flat `Vec` to hold one value per cell.
+///
+/// ```
+/// use aoc_common::{compress::Compressed, interval::{Cuboid, Interval}};
+///
+/// let boxes = [
+///     Cuboid::new([Interval::new(0, 1000), Interval::new(0, 10)]),
+///     Cuboid::new([Interval::new(500, 2000), Interval::new(5, 20)]),
+/// ];
+/// let space = Compressed::of_cuboids(&boxes);
+/// assert_eq!(space.shape(), [3, 3]);
+/// let mut covered = vec![false; space.len()];
+/// for b in &boxes {
+///     space.cells_in(b).for_each(|cell| covered[space.index(cell)] = true);
+/// }
+/// let area = space.cells().filter(|c| covered[space.index(*c)]).map(|c| space.volume(c));
+/// assert_eq!(area.sum::<i64>(), 10_000 + 22_500 - 2_500);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Compressed<const N: usize> {
+    pub axes: [Axis; N],
+}
+
+impl<const N: usize> Compressed<N> {
+    pub fn new(axes: [Axis; N]) -> Self {
+        Self { axes }
+    }
+
+    /// The space cut at the faces of `cuboids`, so that every cell is either
+    /// inside or outside each of them.
+    pub fn of_cuboids<'a>(cuboids: impl IntoIterator<Item = &'a Cuboid<N>>) -> Self {
+        let mut breaks = std::array::from_fn::<_, N, _>(|_| vec![]);
+        for cuboid in cuboids {
+            for (axis, interval) in breaks.iter_mut().zip(cuboid.axes) {
+                axis.extend([interval.start, interval.end]);
+            }
+        }
+        Self::new(breaks.map(Axis::new))
+    }
+
+    /// The space cut around `points`, so that each has a cell of its own.
+    pub fn of_points(points: impl IntoIterator<Item = [i64; N]>) -> Self {
+        let mut breaks = std::array::from_fn::<_, N, _>(|_| vec![]);
+        for point in points {
+            for (axis, n) in breaks.iter_mut().zip(point) {
+                axis.extend([n, n + 1]);
+            }
+        }
+        Self::new(breaks.map(Axis::new))
+    }
+
+    /// Number of cells along every axis.
+    pub fn shape(&self) -> [usize; N] {
+        std::array::from_fn(|i| self.axes[i].len())
+    }
+
+    /// Number of cells in all.
+    pub fn len(&self) -> usize {
+        self.shape().iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Where `cell` goes in a flat `Vec` of [`Self::len`] values.
+    pub fn index(&self, cell: [usize; N]) -> usize {
+        cell.iter()
+            .zip(&self.axes)
+            .fold(0, |index, (i, axis)| index * axis.len() + i)
+    }
+
+    /// The cell holding `point`, if any.
+    pub fn cell(&self, point: [i64; N]) -> Option<[usize; N]> {
+        let mut cell = [0; N];
+        for (i, n) in point.into_iter().enumerate() {
+            cell[i] = self.axes[i].cell(n)?;
+        }
+        Some(cell)
+    }
+
+    /// The real coordinates of `cell`.
+    pub fn cuboid(&self, cell: [usize; N]) -> Cuboid<N> {
+        Cuboid::new(std::array::from_fn(|i| self.axes[i].interval(cell[i])))
+    }
+
+    /// Number of points in `cell`, what it weighs when adding up cells.
+    pub fn volume(&self, cell: [usize; N]) -> i64 {
+        cell.iter()
+            .zip(&self.axes)
+            .map(|(i, axis)| axis.width(*i))
+            .product()
+    }
+
+    /// Every cell, in the order of [`Self::index`].
+    pub fn cells(&self) -> impl Iterator<Item = [usize; N]> {
+        cells(self.shape().map(|len| 0..len))
+    }
+
+    /// The cells making up `cuboid`, whose faces must be breaks, in the order
+    /// of [`Self::index`].
+    pub fn cells_in(&self, cuboid: &Cuboid<N>) -> impl Iterator<Item = [usize; N]> {
+        cells(std::array::from_fn(|i| self.axes[i].cells(&cuboid.axes[i])))
+    }
+}
+
+/// Every combination of indices from `ranges`, the last axis turning fastest.
+fn cells<const N: usize>(ranges: [Range<usize>; N]) -> impl Iterator<Item = [usize; N]> {
+    let mut next = (!ranges.iter().any(Range::is_empty)).then(|| ranges.clone().map(|r| r.start));
+    std::iter::from_fn(move || {
+        let cell = next?;
+        next = None;
+        let mut n = cell;
+        for axis in (0..N).rev() {
+            n[axis] += 1;
+            if n[axis] < ranges[axis].end {
+                next = Some(n);
+                break;
+            }
+            n[axis] = ranges[axis].start;
+        }
+        Some(cell)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_axis() {
+        let axis = Axis::new([10, 3, 7, 3]);
+        assert_eq!(axis.len(), 2);
+        assert_eq!(axis.cell(2), None);
+        assert_eq!(axis.cell(3), Some(0));
+        assert_eq!(axis.cell(9), Some(1));
+        assert_eq!(axis.cell(10), None);
+        assert_eq!(axis.cells(&Interval::new(3, 10)), 0..2);
+        assert_eq!(axis.interval(1), Interval::new(7, 10));
+        assert_eq!(axis.width(0), 4);
+        assert!(Axis::new([5]).is_empty());
+    }
+
+    #[test]
+    fn test_points() {
+        let space = Compressed::of_points([[4, -2], [100, 7], [4, 7]]);
+        // a cell per point and one for each gap between them
+        assert_eq!(space.shape(), [3, 3]);
+        assert_eq!(space.cell([100, -2]), Some([2, 0]));
+        assert_eq!(space.cell([50, 0]), Some([1, 1]));
+        assert_eq!(space.cell([101, 7]), None);
+        assert_eq!(space.volume([2, 2]), 1);
+        assert_eq!(space.volume([1, 1]), 95 * 8);
+        let indices = space.cells().map(|c| space.index(c)).collect::<Vec<_>>();
+        assert_eq!(indices, (0..9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cuboids() {
+        let a = Cuboid::new([
+            Interval::new(0, 4),
+            Interval::new(0, 4),
+            Interval::new(0, 4),
+        ]);
+        let b = Cuboid::new([
+            Interval::new(2, 6),
+            Interval::new(-3, 2),
+            Interval::new(1, 3),
+        ]);
+        let space = Compressed::of_cuboids(&[a, b]);
+        assert_eq!(space.shape(), [3, 3, 3]);
+        let volume = |c: &Cuboid<3>| space.cells_in(c).map(|c| space.volume(c)).sum::<i64>();
+        assert_eq!((volume(&a), volume(&b)), (a.volume(), b.volume()));
+        assert_eq!(
+            space.cells().map(|c| space.volume(c)).sum::<i64>(),
+            6 * 7 * 4
+        );
+        let cell = space.cell([3, 1, 2]).unwrap();
+        assert_eq!(space.cuboid(cell), a.intersection(&b));
+        assert_eq!(
+            space
+                .cells_in(&Cuboid::new([Interval::new(0, 0); 3]))
+                .count(),
+            0
+        );
+    }
+}
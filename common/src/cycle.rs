@@ -0,0 +1,206 @@
+//! Fast-forwarding deterministic simulations: once a state comes back, the
+//! steps after it repeat forever, so any far away step is one of the first
+//! few in disguise.
+
+/// Where the states of a simulation start repeating: the state after
+/// `offset + period` steps is the one after `offset`, and `offset` is the
+/// first step for which that holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub offset: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step among the first `offset + period` left in the same state as
+    /// step `n`.
+    pub fn reduce(&self, n: u64) -> usize {
+        match n.checked_sub(self.offset as u64) {
+            Some(rest) => self.offset + (rest % self.period as u64) as usize,
+            None => n as usize,
+        }
+    }
+
+    /// The sum of what the first `n` steps add up, given `totals[k]` for the
+    /// sum of the first `k` steps up to `k = offset + period`.
+    pub fn total(&self, totals: &[u64], n: u64) -> u64 {
+        let end = self.offset + self.period;
+        if n <= end as u64 {
+            return totals[n as usize];
+        }
+        let laps = (n - self.offset as u64) / self.period as u64;
+        totals[self.reduce(n)] + laps * (totals[end] - totals[self.offset])
+    }
+}
+
+/// The cycle the states from `start` run into, with Brent's algorithm: the
+/// states are only compared, never stored. `None` when the search takes more
+/// than `limit` steps, which it does when there is no cycle at all.
+pub fn find<S: Clone + Eq>(start: &S, mut step: impl FnMut(&S) -> S, limit: u64) -> Option<Cycle> {
+    // the tortoise waits at powers of two for the hare to come round to it
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    let mut steps = 1;
+    while tortoise != hare {
+        if steps >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+    // a period apart from the start, the two meet where the cycle begins
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start.clone(), |s, _| step(&s));
+    let mut offset = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        offset += 1;
+    }
+    Some(Cycle { offset, period })
+}
+
+/// How many steps from `start` it takes to reach a state `done` holds for,
+/// and that state. Every state is only stepped to once, so `step` may also
+/// watch them go by. `None` when the states come back round without `done`
+/// ever holding, which Brent's algorithm notices after a few times as many
+/// steps as there are states in all.
+///
+/// ```
+/// use aoc_common::cycle;
+///
+/// let step = |x: &u64| (x * x + 1) % 1000;
+/// assert_eq!(cycle::until(2, step, |x| *x == 677), Some((3, 677)));
+/// assert_eq!(cycle::until(2, step, |x| *x == 3), None);
+/// ```
+pub fn until<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut done: impl FnMut(&S) -> bool,
+) -> Option<(u64, S)> {
+    let (mut power, mut period) = (1, 0);
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    let mut steps = 0;
+    loop {
+        hare = step(&hare);
+        steps += 1;
+        period += 1;
+        if done(&hare) {
+            return Some((steps, hare));
+        }
+        if hare == tortoise {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+    }
+}
+
+/// The state `n` steps from `start`, skipping the laps around any cycle.
+///
+/// ```
+/// use aoc_common::cycle;
+///
+/// let step = |x: &u64| (x * x + 1) % 1000;
+/// assert_eq!(cycle::nth(2, step, 1_000_000_000_000), 330);
+/// ```
+pub fn nth<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S, n: u64) -> S {
+    let steps = match find(&start, &mut step, n) {
+        Some(cycle) => cycle.reduce(n) as u64,
+        None => n,
+    };
+    (0..steps).fold(start, |s, _| step(&s))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_find() {
+        let mut rng = Rng::new(0);
+        for _ in 0..200 {
+            let size = rng.below(40) + 1;
+            let next = (0..size).map(|_| rng.below(size)).collect::<Vec<_>>();
+            let start = rng.below(size);
+            let step = |s: &usize| next[*s];
+            let mut seen = HashMap::new();
+            let mut state = start;
+            for n in 0.. {
+                if let Some(offset) = seen.insert(state, n) {
+                    let expected = Cycle {
+                        offset,
+                        period: n - offset,
+                    };
+                    assert_eq!(find(&start, step, u64::MAX), Some(expected));
+                    break;
+                }
+                state = step(&state);
+            }
+            let direct = (0..100).fold(start, |s, _| step(&s));
+            assert_eq!(nth(start, step, 100), direct);
+        }
+        // counting up never repeats
+        assert_eq!(find(&0, |n: &u64| n + 1, 1000), None);
+        assert_eq!(nth(0, |n: &u64| n + 1, 1000), 1000);
+    }
+
+    #[test]
+    fn test_until() {
+        let mut rng = Rng::new(1);
+        for _ in 0..200 {
+            let size = rng.below(40) + 1;
+            let next = (0..size).map(|_| rng.below(size)).collect::<Vec<_>>();
+            let (start, target) = (rng.below(size), rng.below(size));
+            let step = |s: &usize| next[*s];
+            // the states from start, up to the first one seen twice
+            let mut seen = vec![];
+            let mut state = step(&start);
+            while !seen.contains(&state) {
+                seen.push(state);
+                state = step(&state);
+            }
+            let expected = seen
+                .iter()
+                .position(|s| *s == target)
+                .map(|i| (i as u64 + 1, target));
+            let mut stepped = 0;
+            let counted = |s: &usize| {
+                stepped += 1;
+                step(s)
+            };
+            let found = until(start, counted, |s| *s == target);
+            assert_eq!(found, expected);
+            assert!(stepped <= 4 * seen.len());
+        }
+    }
+
+    #[test]
+    fn test_total() {
+        // 4 steps adding 1 each, then a cycle of 3 adding 10, 20 and 30
+        let cycle = Cycle {
+            offset: 4,
+            period: 3,
+        };
+        let totals = [0, 1, 2, 3, 4, 14, 34, 64];
+        assert_eq!(cycle.total(&totals, 3), 3);
+        assert_eq!(cycle.total(&totals, 7), 64);
+        assert_eq!(cycle.total(&totals, 12), 64 + 60 + 10 + 20);
+        assert_eq!(cycle.reduce(12), 6);
+        assert_eq!(cycle.reduce(2), 2);
+    }
+}
@@ -0,0 +1,353 @@
+//! Frames recorded from grid simulations, to watch them in a terminal or
+//! export them as an animated GIF or a PNG sequence (the latter two behind
+//! the `export` feature).
+
+use std::{io::Write, time::Duration};
+
+use thiserror::Error;
+
+use crate::{grid::Grid, parse::ParseError, solution::Solution};
+
+/// One step of a simulation, a character per cell. Colours come from
+/// [`color`], so simulations stick to its characters where they can.
+pub type Frame = Grid<char>;
+
+/// A [`Solution`] with a simulation worth watching.
+pub trait Visualise: Solution {
+    /// Run the simulation on `input`, recording a frame per step.
+    fn visualise(input: &Self::Input, recorder: &mut Recorder);
+}
+
+/// Parse `input` and record the frames of `S`. Type erased like
+/// [`crate::solution::solve_observed`].
+pub fn record_frames<S: Visualise>(input: &str, recorder: &mut Recorder) -> Result<(), ParseError> {
+    S::visualise(&S::parse(input)?, recorder);
+    Ok(())
+}
+
+/// Collects the frames a simulation offers. Frames are rendered lazily, so a
+/// recorder that is [`off`](Recorder::off) or skipping a frame costs a
+/// counter increment.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    enabled: bool,
+    every: usize,
+    limit: usize,
+    offered: usize,
+}
+
+impl Recorder {
+    /// A recorder keeping every `every`th frame offered, up to `limit` of them.
+    pub fn new(every: usize, limit: usize) -> Self {
+        Self {
+            frames: vec![],
+            enabled: true,
+            every: every.max(1),
+            limit,
+            offered: 0,
+        }
+    }
+
+    /// A recorder that keeps nothing, for solving without watching.
+    pub fn off() -> Self {
+        Self {
+            enabled: false,
+            ..Self::new(1, 0)
+        }
+    }
+
+    /// Offer the frame `render` draws, which is only called if it is kept.
+    pub fn record(&mut self, render: impl FnOnce() -> Frame) {
+        if !self.enabled {
+            return;
+        }
+        let n = self.offered;
+        self.offered += 1;
+        if n.is_multiple_of(self.every) && self.frames.len() < self.limit {
+            self.frames.push(render());
+        }
+    }
+
+    /// How many frames the simulation offered, kept or not.
+    pub fn offered(&self) -> usize {
+        self.offered
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("no frames recorded")]
+    Empty,
+    #[error("{0} distinct cell characters, a GIF holds 256 colours at most")]
+    TooManyColors(usize),
+    #[error("{width}x{height} pixels is too large for a GIF")]
+    TooLarge { width: usize, height: usize },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "export")]
+    #[error("encode GIF: {0}")]
+    Gif(#[from] gif::EncodingError),
+    #[cfg(feature = "export")]
+    #[error("encode PNG: {0}")]
+    Png(#[from] png::EncodingError),
+}
+
+const BACKGROUND: [u8; 3] = [15, 15, 35];
+
+/// The colour of a cell: `.` and space are background, `#` is white, the
+/// digits go from dark blue to yellow, `H` and the arrows have their own
+/// colour, anything else gets one made up from its code.
+pub fn color(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => BACKGROUND,
+        '#' => [240, 240, 240],
+        '>' => [255, 140, 0],
+        'v' => [0, 180, 255],
+        'H' => [230, 40, 40],
+        '0'..='9' => {
+            let d = c as i32 - '0' as i32;
+            let mix = |from: i32, to: i32| (from + (to - from) * d / 9) as u8;
+            [mix(40, 250), mix(40, 220), mix(120, 60)]
+        }
+        _ => {
+            let h = (c as u32).wrapping_mul(2_654_435_761);
+            [
+                (h >> 24) as u8 | 0x40,
+                (h >> 16) as u8 | 0x40,
+                (h >> 8) as u8 | 0x40,
+            ]
+        }
+    }
+}
+
+/// Height and width fitting every frame, smaller frames being centred on it.
+fn canvas(frames: &[Frame]) -> (usize, usize) {
+    frames
+        .iter()
+        .fold((0, 0), |(h, w), f| (h.max(f.height()), w.max(f.width())))
+}
+
+/// The cell of `frame` shown at `(row, col)` of a canvas `height` by
+/// `width`, background outside the frame.
+fn cell_at(frame: &Frame, (height, width): (usize, usize), (row, col): (usize, usize)) -> char {
+    let top = (height - frame.height()) / 2;
+    let left = (width - frame.width()) / 2;
+    row.checked_sub(top)
+        .zip(col.checked_sub(left))
+        .and_then(|pos| frame.get(pos))
+        .copied()
+        .unwrap_or('.')
+}
+
+/// Draw `frame` on a canvas, `scale` pixels per cell side, `pixel` turning a
+/// cell into the bytes of a pixel.
+fn raster<P: Copy>(
+    frame: &Frame,
+    canvas: (usize, usize),
+    scale: usize,
+    pixel: impl Fn(char) -> P,
+) -> Vec<P> {
+    let mut pixels = Vec::with_capacity(canvas.0 * canvas.1 * scale * scale);
+    for row in 0..canvas.0 {
+        let line = (0..canvas.1)
+            .flat_map(|col| std::iter::repeat_n(pixel(cell_at(frame, canvas, (row, col))), scale))
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+    pixels
+}
+
+/// Play `frames` on a terminal, `fps` of them a second, coloured with ANSI
+/// true colour escapes.
+pub fn play(frames: &[Frame], fps: u32, out: &mut impl Write) -> Result<(), ExportError> {
+    if frames.is_empty() {
+        return Err(ExportError::Empty);
+    }
+    let delay = Duration::from_secs(1) / fps.max(1);
+    let canvas = canvas(frames);
+    write!(out, "\x1b[2J")?;
+    for (i, frame) in frames.iter().enumerate() {
+        let mut screen = String::from("\x1b[H");
+        for row in 0..canvas.0 {
+            for col in 0..canvas.1 {
+                let c = cell_at(frame, canvas, (row, col));
+                let [r, g, b] = color(c);
+                screen.push_str(&format!("\x1b[38;2;{r};{g};{b}m{c}"));
+            }
+            screen.push_str("\x1b[0m\n");
+        }
+        screen.push_str(&format!("frame {}/{}\n", i + 1, frames.len()));
+        out.write_all(screen.as_bytes())?;
+        out.flush()?;
+        if i + 1 < frames.len() {
+            std::thread::sleep(delay);
+        }
+    }
+    Ok(())
+}
+
+/// Write `frames` as a looping GIF, `fps` of them a second, `scale` pixels
+/// per cell side.
+#[cfg(feature = "export")]
+pub fn write_gif(
+    frames: &[Frame],
+    fps: u32,
+    scale: usize,
+    out: impl Write,
+) -> Result<(), ExportError> {
+    if frames.is_empty() {
+        return Err(ExportError::Empty);
+    }
+    let mut chars = frames
+        .iter()
+        .flat_map(|f| f.cells().iter().copied())
+        .chain(['.'])
+        .collect::<Vec<_>>();
+    chars.sort_unstable();
+    chars.dedup();
+    if chars.len() > 256 {
+        return Err(ExportError::TooManyColors(chars.len()));
+    }
+    let palette = chars.iter().flat_map(|&c| color(c)).collect::<Vec<_>>();
+
+    let canvas = canvas(frames);
+    let (height, width) = (canvas.0 * scale.max(1), canvas.1 * scale.max(1));
+    let (Ok(h), Ok(w)) = (u16::try_from(height), u16::try_from(width)) else {
+        return Err(ExportError::TooLarge { width, height });
+    };
+    let mut encoder = gif::Encoder::new(out, w, h, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    // in hundredths of a second
+    let delay = (100 / fps.max(1)).max(1) as u16;
+    for frame in frames {
+        let index = |c: char| chars.binary_search(&c).unwrap() as u8;
+        let gif_frame = gif::Frame {
+            width: w,
+            height: h,
+            delay,
+            buffer: raster(frame, canvas, scale.max(1), index).into(),
+            ..Default::default()
+        };
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+/// Write each frame to `dir` as `frame-0001.png` and so on, `scale` pixels
+/// per cell side. Returns the files written.
+#[cfg(feature = "export")]
+pub fn write_pngs(
+    frames: &[Frame],
+    scale: usize,
+    dir: &std::path::Path,
+) -> Result<Vec<std::path::PathBuf>, ExportError> {
+    if frames.is_empty() {
+        return Err(ExportError::Empty);
+    }
+    std::fs::create_dir_all(dir)?;
+    let canvas = canvas(frames);
+    let scale = scale.max(1);
+    let digits = frames.len().to_string().len().max(4);
+    let mut files = vec![];
+    for (i, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("frame-{:0digits$}.png", i + 1));
+        let file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        let mut encoder =
+            png::Encoder::new(file, (canvas.1 * scale) as u32, (canvas.0 * scale) as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let pixels = raster(frame, canvas, scale, color);
+        encoder
+            .write_header()?
+            .write_image_data(pixels.as_flattened())?;
+        files.push(path);
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame(s: &str) -> Frame {
+        Grid::parse(s, Some).unwrap()
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::new(2, 2);
+        for i in 0..6 {
+            recorder.record(|| frame(&i.to_string()));
+        }
+        assert_eq!(recorder.offered(), 6);
+        assert_eq!(recorder.into_frames(), [frame("0"), frame("2")]);
+
+        let mut off = Recorder::off();
+        off.record(|| unreachable!("a recorder that is off renders nothing"));
+        assert!(off.frames().is_empty());
+    }
+
+    #[test]
+    fn test_play_centres_smaller_frames() {
+        let frames = [frame("#"), frame("v.v\n.>.\nv.v")];
+        let mut out = vec![];
+        play(&frames, 1000, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            plain(&out),
+            "...\n.#.\n...\nframe 1/2\nv.v\n.>.\nv.v\nframe 2/2\n"
+        );
+        assert!(out.contains("\x1b[38;2;240;240;240m#"));
+        assert!(matches!(play(&[], 1, &mut vec![]), Err(ExportError::Empty)));
+    }
+
+    /// `s` without its ANSI escapes.
+    fn plain(s: &str) -> String {
+        let mut plain = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+
+    #[cfg(feature = "export")]
+    #[test]
+    fn test_export() {
+        let frames = [frame(".#\n#."), frame("12\n34")];
+        let mut gif = vec![];
+        write_gif(&frames, 10, 3, &mut gif).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(&gif[..]).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 6));
+        let mut decoded = 0;
+        while let Some(f) = decoder.read_next_frame().unwrap() {
+            assert_eq!(f.delay, 10);
+            decoded += 1;
+        }
+        assert_eq!(decoded, 2);
+
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let files = write_pngs(&frames, 2, &dir).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files[1].ends_with("frame-0002.png"));
+        let png = png::Decoder::new(std::fs::File::open(&files[0]).unwrap());
+        let reader = png.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (4, 4));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+//! Seeded generators of valid puzzle inputs of any size, to find where a
+//! solution slows down before a real input does.
+
+use std::ops::RangeInclusive;
+
+use crate::solution::Solution;
+
+/// A small deterministic random number generator (SplitMix64), so a seed
+/// gives the same input on every machine and build.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` being positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {low}..={high}");
+        let span = high.abs_diff(low).wrapping_add(1);
+        let offset = if span == 0 {
+            self.next_u64()
+        } else {
+            self.next_u64() % span
+        };
+        low.wrapping_add(offset as i64)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A [`Solution`] that can make up inputs of its own.
+pub trait Generate: Solution {
+    /// A valid input that both parts can answer. What `size` counts (lines,
+    /// cuboids, monkeys...) is up to the day, bigger meaning more work.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// The input `S` generates from `seed`. Type erased like
+/// [`crate::solution::solve_observed`].
+pub fn generate_input<S: Generate>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert!(draw(7).iter().all(|n| (-3..=3).contains(n)));
+        for n in -3..=3 {
+            assert!(draw(7).contains(&n));
+        }
+        let mut rng = Rng::new(0);
+        assert!(rng.range(i64::MIN..=i64::MAX) != rng.range(i64::MIN..=i64::MAX));
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..10).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+    }
+}
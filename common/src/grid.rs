@@ -0,0 +1,469 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use thiserror::Error;
+
+use crate::parse::ParseError;
+
+pub use aoc_macros::Cell;
+
+/// A cell position as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// Offsets of the up, down, left and right neighbours.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+/// Offsets of all 8 surrounding cells, row by row.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GridError {
+    #[error("row {row} has {found} cells, expected {expected}")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("invalid cell {ch:?} at row {row}, col {col}")]
+    InvalidCell { row: usize, col: usize, ch: char },
+}
+
+/// A character that is none of the cells of a [`Cell`] type.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("invalid cell {ch:?}, expected one of {expected}")]
+pub struct CellError {
+    pub ch: char,
+    pub expected: &'static str,
+}
+
+/// A cell of a puzzle map drawn with one character each, usually derived:
+///
+/// ```
+/// use aoc_common::grid::{Cell, Grid};
+///
+/// #[derive(Cell, Clone, Copy, PartialEq, Debug)]
+/// enum Square {
+///     #[cell('.')]
+///     Open,
+///     #[cell('#')]
+///     Wall,
+///     #[cell('0'..='9')]
+///     Mark(char),
+/// }
+///
+/// let map = Grid::<Square>::parse_cells(".#\n1.").unwrap();
+/// assert_eq!(map[(1, 0)], Square::Mark('1'));
+/// assert_eq!(map.to_string(), ".#\n1.");
+/// ```
+///
+/// The derive also implements `TryFrom<char>` failing with a [`CellError`],
+/// `From<Self> for char` and `Display`.
+pub trait Cell: TryFrom<char, Error = CellError> + Display {
+    /// The character the cell is drawn with.
+    fn symbol(&self) -> char;
+}
+
+impl From<GridError> for ParseError {
+    fn from(e: GridError) -> Self {
+        match e {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => Self {
+                line: row + 1,
+                column: expected.min(found) + 1,
+                token: String::new(),
+                message: format!("expected {expected} cells in the row, found {found}"),
+            },
+            GridError::InvalidCell { row, col, ch } => Self {
+                line: row + 1,
+                column: col + 1,
+                token: ch.to_string(),
+                message: "invalid cell".to_string(),
+            },
+        }
+    }
+}
+
+/// A dense rectangular 2D map stored row by row.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let mut cells = Vec::with_capacity(height * width);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(f((row, col)));
+            }
+        }
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, GridError> {
+        let mut grid = Self {
+            cells: vec![],
+            height: 0,
+            width: 0,
+        };
+        for (row, cells) in rows.into_iter().enumerate() {
+            if row == 0 {
+                grid.width = cells.len();
+            } else if cells.len() != grid.width {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: grid.width,
+                    found: cells.len(),
+                });
+            }
+            grid.cells.extend(cells);
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    /// Build a grid from a character map, one line per row. `f` returns
+    /// `None` for characters that are not valid cells.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut rows = vec![];
+        for (row, line) in s.lines().enumerate() {
+            let mut cells = vec![];
+            for (col, ch) in line.chars().enumerate() {
+                cells.push(f(ch).ok_or(GridError::InvalidCell { row, col, ch })?);
+            }
+            rows.push(cells);
+        }
+        Self::from_rows(rows)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Grid::get`] but takes signed coordinates, so callers can probe
+    /// around the border without underflowing.
+    pub fn get_checked(&self, row: isize, col: isize) -> Option<&T> {
+        self.get((usize::try_from(row).ok()?, usize::try_from(col).ok()?))
+    }
+
+    pub fn get_checked_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        self.get_mut((usize::try_from(row).ok()?, usize::try_from(col).ok()?))
+    }
+
+    /// Toroidal access: coordinates past one edge continue from the opposite
+    /// edge. `None` only when the grid is empty.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        self.wrap(row, col).map(|pos| &self[pos])
+    }
+
+    pub fn get_wrapping_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        let pos = self.wrap(row, col)?;
+        Some(&mut self[pos])
+    }
+
+    fn wrap(&self, row: isize, col: isize) -> Option<Pos> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        Some((
+            row.rem_euclid(self.height as isize) as usize,
+            col.rem_euclid(self.width as isize) as usize,
+        ))
+    }
+
+    /// The position `delta` away from `pos`, if it is still on the grid.
+    pub fn offset(&self, pos: Pos, delta: (isize, isize)) -> Option<Pos> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// The position `delta` away from `pos` on a grid wrapping around its
+    /// edges. `pos` must be on the grid, which it cannot be when empty.
+    pub fn wrapping_offset(&self, pos: Pos, delta: (isize, isize)) -> Pos {
+        self.wrap(pos.0 as isize + delta.0, pos.1 as isize + delta.1)
+            .expect("an offset from a position on an empty grid")
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4
+            .iter()
+            .filter_map(move |delta| self.offset(pos, *delta))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8
+            .iter()
+            .filter_map(move |delta| self.offset(pos, *delta))
+    }
+
+    /// Positions walked from `pos` (excluded) in steps of `delta` until the
+    /// edge of the grid.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, delta), move |p| self.offset(*p, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() rejects a zero size, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of grid");
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// Cells from `pos` towards the bottom right corner.
+    pub fn diagonal(&self, pos: Pos) -> impl Iterator<Item = &T> {
+        std::iter::once(pos)
+            .chain(self.ray(pos, (1, 1)))
+            .map(|p| &self[p])
+    }
+
+    /// Cells from `pos` towards the bottom left corner.
+    pub fn anti_diagonal(&self, pos: Pos) -> impl Iterator<Item = &T> {
+        std::iter::once(pos)
+            .chain(self.ray(pos, (1, -1)))
+            .map(|p| &self[p])
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    /// Build a grid of [`Cell`]s from their characters, one line per row.
+    pub fn parse_cells(s: &str) -> Result<Self, GridError> {
+        Self::parse(s, |c| T::try_from(c).ok())
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a map of single decimal digits such as a height map.
+    pub fn parse_digits(s: &str) -> Result<Self, GridError> {
+        Self::parse(s, |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        assert!(self.contains(pos), "{pos:?} out of grid");
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        assert!(self.contains(pos), "{pos:?} out of grid");
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static SAMPLE: &str = "123\n456";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse_digits(SAMPLE).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.to_string(), SAMPLE);
+        assert_eq!(
+            Grid::parse_digits("12\n345"),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Grid::parse_digits("12\n3x"),
+            Err(GridError::InvalidCell {
+                row: 1,
+                col: 1,
+                ch: 'x'
+            })
+        );
+        assert_eq!(Grid::parse_digits("").unwrap().height(), 0);
+    }
+
+    #[derive(Cell, Debug, Clone, Copy, PartialEq, Eq)]
+    enum Square {
+        #[cell('.')]
+        Open,
+        #[cell('#')]
+        Wall,
+        #[cell('a'..='z' | 'S')]
+        Mark(char),
+    }
+
+    #[test]
+    fn test_cells() {
+        assert_eq!(Square::try_from('#'), Ok(Square::Wall));
+        assert_eq!(Square::try_from('S'), Ok(Square::Mark('S')));
+        assert_eq!(char::from(Square::Mark('q')), 'q');
+        let e = Square::try_from('?').unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid cell '?', expected one of '.', '#', 'a'..='z'|'S'"
+        );
+        let map = "S.#\n.b.";
+        let grid = Grid::<Square>::parse_cells(map).unwrap();
+        assert_eq!(grid[(0, 2)], Square::Wall);
+        assert_eq!(grid.to_string(), map);
+        assert_eq!(
+            Grid::<Square>::parse_cells("..\n.X"),
+            Err(GridError::InvalidCell {
+                row: 1,
+                col: 1,
+                ch: 'X'
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_and_wrapping_access() {
+        let grid = Grid::parse_digits(SAMPLE).unwrap();
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_checked(-1, 0), None);
+        assert_eq!(grid.get_checked(0, 2), Some(&3));
+        assert_eq!(grid.get_wrapping(-1, -1), Some(&6));
+        assert_eq!(grid.get_wrapping(2, 4), Some(&2));
+        assert_eq!(Grid::<u8>::default().get_wrapping(0, 0), None);
+        assert_eq!(grid.wrapping_offset((1, 2), (1, 1)), (0, 0));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::parse_digits("123\n456\n789").unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(
+            grid.diagonal((0, 0)).copied().collect::<Vec<_>>(),
+            [1, 5, 9]
+        );
+        assert_eq!(
+            grid.anti_diagonal((0, 2)).copied().collect::<Vec<_>>(),
+            [3, 5, 7]
+        );
+        assert_eq!(grid.ray((1, 1), (0, -1)).collect::<Vec<_>>(), [(1, 0)]);
+        assert_eq!(grid.ray((2, 0), (1, 0)).count(), 0);
+        assert_eq!(grid.position(|c| *c == 8), Some((2, 1)));
+    }
+}
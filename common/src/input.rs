@@ -0,0 +1,174 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+use crate::parse::ParseError;
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("read stdin: {0}")]
+    Stdin(std::io::Error),
+    #[error("no input for {year} day {day} under {dir}")]
+    NotFound { dir: PathBuf, year: u16, day: u8 },
+    #[error("malformed input: {0}")]
+    Parse(#[from] ParseError),
+}
+
+/// Where puzzle inputs are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    /// A single input file, whatever the day.
+    File(PathBuf),
+    /// A tree of inputs, looked up per day with [`day_path`].
+    Dir(PathBuf),
+}
+
+impl Source {
+    /// `-` selects stdin, an existing directory selects per-day lookup and
+    /// anything else is read as a plain file.
+    pub fn from_arg(arg: impl Into<PathBuf>) -> Self {
+        let path = arg.into();
+        if path.as_os_str() == "-" {
+            Self::Stdin
+        } else if path.is_dir() {
+            Self::Dir(path)
+        } else {
+            Self::File(path)
+        }
+    }
+
+    /// Whether the source can feed more than one day.
+    pub fn is_shared(&self) -> bool {
+        matches!(self, Self::Dir(_))
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut s = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut s)
+                    .map_err(InputError::Stdin)?;
+                Ok(normalize(s))
+            }
+            Self::File(path) => read_file(path),
+            Self::Dir(dir) => match day_path(dir, year, day) {
+                Some(path) => read_file(&path),
+                None => Err(InputError::NotFound {
+                    dir: dir.clone(),
+                    year,
+                    day,
+                }),
+            },
+        }
+    }
+}
+
+/// Find the input of one day inside `dir`, accepting either a plain
+/// `<year>/day-<n>` file or a day directory holding `input` or `src/input`
+/// (the layout of this repository).
+pub fn day_path(dir: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    let day_dir = dir.join(year.to_string()).join(format!("day-{day}"));
+    if day_dir.is_file() {
+        return Some(day_dir);
+    }
+    ["src/input", "input"]
+        .iter()
+        .map(|name| day_dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Input of a standalone day binary: the first command line argument (`-` for
+/// stdin) if there is one, otherwise `src/input` of the day crate.
+pub fn from_args(manifest_dir: &str) -> Result<String, InputError> {
+    match std::env::args_os().nth(1) {
+        Some(arg) if arg == "-" => Source::Stdin.read(0, 0),
+        Some(arg) => read_file(Path::new(&arg)),
+        None => read_file(&Path::new(manifest_dir).join("src/input")),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path)
+        .map(normalize)
+        .map_err(|source| InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+// hand-copied inputs usually lack the trailing newline a downloaded one has,
+// strip it so both parse the same
+fn normalize(mut s: String) -> String {
+    let len = s.trim_end_matches(['\n', '\r']).len();
+    s.truncate(len);
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_day_path_layouts() {
+        let dir = scratch_dir("layouts");
+        std::fs::create_dir_all(dir.join("2021/day-15/src")).unwrap();
+        std::fs::write(dir.join("2021/day-15/src/input"), "15\n").unwrap();
+        std::fs::create_dir_all(dir.join("2021/day-16")).unwrap();
+        std::fs::write(dir.join("2021/day-16/input"), "16").unwrap();
+        std::fs::create_dir_all(dir.join("2022")).unwrap();
+        std::fs::write(dir.join("2022/day-1"), "1\r\n\n").unwrap();
+
+        let source = Source::from_arg(&dir);
+        assert_eq!(source, Source::Dir(dir.clone()));
+        assert_eq!(source.read(2021, 15).unwrap(), "15");
+        assert_eq!(source.read(2021, 16).unwrap(), "16");
+        assert_eq!(source.read(2022, 1).unwrap(), "1");
+        assert!(matches!(
+            source.read(2022, 2),
+            Err(InputError::NotFound {
+                year: 2022,
+                day: 2,
+                ..
+            })
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("no/such/input"),
+            Source::File("no/such/input".into())
+        );
+        assert!(matches!(
+            Source::from_arg("no/such/input").read(2021, 1),
+            Err(InputError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_normalize_keeps_leading_whitespace() {
+        assert_eq!(
+            normalize("    [D]\n[N] [C]\n\n".to_string()),
+            "    [D]\n[N] [C]"
+        );
+    }
+}
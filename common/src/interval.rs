@@ -0,0 +1,360 @@
+//! Ranges of integers: single intervals, sets of them and boxes made of one
+//! interval per axis.
+
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`, empty when
+/// `end <= start`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The integers from `first` to `last`, both included, as the puzzles
+    /// write them. `None` when `last` is `i64::MAX`, which has no end past
+    /// it.
+    pub const fn inclusive(first: i64, last: i64) -> Option<Self> {
+        match last.checked_add(1) {
+            Some(end) => Some(Self::new(first, end)),
+            None => None,
+        }
+    }
+
+    /// The last integer in, if not empty.
+    pub fn last(&self) -> i64 {
+        self.end - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of integers in.
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        self.start <= n && n < self.end
+    }
+
+    /// Whether every integer of `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether some integer is in both.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The integers in both, possibly none.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Any set of integers, kept as sorted intervals that neither overlap nor
+/// touch.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The merged intervals, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in, the covered length of all intervals.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        self.find(n).is_ok()
+    }
+
+    /// Whether every integer of `interval` is in the set.
+    pub fn covers(&self, interval: &Interval) -> bool {
+        interval.is_empty()
+            || matches!(self.find(interval.start), Ok(i) if self.intervals[i].covers(interval))
+    }
+
+    /// `Ok` with the interval holding `n`, or `Err` with where one starting
+    /// at `n` would go.
+    fn find(&self, n: i64) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|interval| {
+            if interval.end <= n {
+                std::cmp::Ordering::Less
+            } else if interval.start > n {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+    }
+
+    /// Add the integers of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last].iter().fold(interval, |a, b| {
+            Interval::new(a.start.min(b.start), a.end.max(b.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Take out the integers of `interval`.
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        let left = self.intervals[first..last]
+            .first()
+            .map(|i| Interval::new(i.start, interval.start));
+        let right = self.intervals[first..last]
+            .last()
+            .map(|i| Interval::new(interval.end, i.end));
+        let kept = [left, right]
+            .into_iter()
+            .flatten()
+            .filter(|i| !i.is_empty());
+        self.intervals.splice(first..last, kept.collect::<Vec<_>>());
+    }
+
+    /// The integers in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.intervals.iter().for_each(|i| union.insert(*i));
+        union
+    }
+
+    /// The integers in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            let both = x.intersection(&y);
+            if !both.is_empty() {
+                intervals.push(both);
+            }
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.intervals.iter().for_each(|i| difference.remove(*i));
+        difference
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        intervals.into_iter().for_each(|i| set.insert(i));
+        set
+    }
+}
+
+/// A box of `N` dimensions, one interval per axis: a rectangle when `N` is
+/// 2, a cuboid when it is 3. Empty when any of its intervals is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub axes: [Interval; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub const fn new(axes: [Interval; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    /// Number of integer points in.
+    pub fn volume(&self) -> i64 {
+        self.axes.iter().map(Interval::len).product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, n)| axis.contains(n))
+    }
+
+    /// Whether every point of `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || self.axes.iter().zip(&other.axes).all(|(a, b)| a.covers(b))
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The points in both, possibly none.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            self.axes[i].intersection(&other.axes[i])
+        }))
+    }
+
+    /// The points of `self` outside `other`, as at most `2 * N` boxes that do
+    /// not overlap.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let cut = self.intersection(other);
+        if cut.is_empty() {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+        let mut pieces = vec![];
+        let mut rest = *self;
+        for axis in 0..N {
+            let (inner, outer) = (cut.axes[axis], rest.axes[axis]);
+            for slab in [
+                Interval::new(outer.start, inner.start),
+                Interval::new(inner.end, outer.end),
+            ] {
+                if !slab.is_empty() {
+                    let mut piece = rest;
+                    piece.axes[axis] = slab;
+                    pieces.push(piece);
+                }
+            }
+            rest.axes[axis] = inner;
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let inclusive = |first, last| Interval::inclusive(first, last).unwrap();
+        let a = inclusive(2, 8);
+        assert_eq!((a.len(), a.last()), (7, 8));
+        assert!(a.contains(8) && !a.contains(9));
+        assert!(a.covers(&inclusive(3, 8)));
+        assert!(!a.covers(&inclusive(3, 9)));
+        assert!(a.overlaps(&inclusive(8, 9)));
+        assert!(!a.overlaps(&inclusive(9, 9)));
+        assert_eq!(Interval::inclusive(0, i64::MAX), None);
+        assert!(a.intersection(&Interval::new(20, 30)).is_empty());
+        assert_eq!(Interval::new(5, 1).len(), 0);
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let mut set = [(0, 3), (10, 12), (5, 6)]
+            .into_iter()
+            .map(|(a, b)| Interval::new(a, b))
+            .collect::<IntervalSet>();
+        assert_eq!(set.len(), 6);
+        // touching intervals merge
+        set.insert(Interval::new(3, 5));
+        assert_eq!(
+            set.intervals(),
+            [Interval::new(0, 6), Interval::new(10, 12)]
+        );
+        set.remove(Interval::new(2, 11));
+        assert_eq!(
+            set.intervals(),
+            [Interval::new(0, 2), Interval::new(11, 12)]
+        );
+        assert!(set.contains(1) && !set.contains(2) && set.contains(11));
+        assert!(set.covers(&Interval::new(0, 2)) && !set.covers(&Interval::new(0, 3)));
+
+        let other = IntervalSet::from_iter([Interval::new(1, 20)]);
+        assert_eq!(set.union(&other).intervals(), [Interval::new(0, 20)]);
+        assert_eq!(
+            set.intersection(&other).intervals(),
+            [Interval::new(1, 2), Interval::new(11, 12)]
+        );
+        assert_eq!(set.difference(&other).intervals(), [Interval::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_interval_set_against_points() {
+        use crate::generate::Rng;
+        use std::collections::BTreeSet;
+
+        let mut rng = Rng::new(0);
+        for _ in 0..200 {
+            let (mut set, mut points) = (IntervalSet::new(), BTreeSet::new());
+            for _ in 0..rng.below(8) {
+                let start = rng.range(0..=30);
+                let interval = Interval::new(start, start + rng.range(0..=8));
+                let range = interval.start..interval.end;
+                if rng.chance(0.6) {
+                    set.insert(interval);
+                    points.extend(range);
+                } else {
+                    set.remove(interval);
+                    range.for_each(|n| {
+                        points.remove(&n);
+                    });
+                }
+            }
+            assert_eq!(set.len(), points.len() as i64);
+            assert!((0..40).all(|n| set.contains(n) == points.contains(&n)));
+            assert!(set.intervals().windows(2).all(|w| w[0].end < w[1].start));
+        }
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::new([Interval::new(0, 4), Interval::new(0, 4)]);
+        let b = Cuboid::new([Interval::new(1, 2), Interval::new(2, 6)]);
+        assert_eq!(a.volume(), 16);
+        assert!(a.contains([3, 0]) && !a.contains([4, 0]));
+        assert!(a.overlaps(&b) && !a.covers(&b));
+        assert_eq!(a.intersection(&b).volume(), 2);
+        let pieces = a.difference(&b);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i64>(), 14);
+        assert!(pieces.iter().all(|p| a.covers(p) && !p.overlaps(&b)));
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(pieces[i + 1..].iter().all(|q| !p.overlaps(q)));
+        }
+        let far = Cuboid::new([Interval::new(10, 11); 2]);
+        assert_eq!(a.difference(&far), [a]);
+    }
+}
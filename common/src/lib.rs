@@ -0,0 +1,19 @@
+//! Helpers shared by the runner and every day crate.
+
+// lets the derives name `::aoc_common` in this crate's own tests too
+extern crate self as aoc_common;
+
+pub mod compress;
+pub mod cycle;
+pub mod frames;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod matrix;
+pub mod memo;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod solution;
@@ -0,0 +1,265 @@
+//! Square matrices raised to huge powers, for linear recurrences: when one
+//! step of a simulation is a matrix times the state, step `n` is the `n`th
+//! power of it times the start, found in `log n` products.
+
+use std::fmt;
+
+pub use num_bigint::BigUint;
+
+/// What a [`Matrix`] holds: numbers that add and multiply.
+pub trait Element: Clone + fmt::Debug {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_u64(n: u64) -> Self;
+    fn is_zero(&self) -> bool;
+    fn add(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+}
+
+macro_rules! checked_element {
+    ($($int:ty),+) => {
+        $(
+            /// Panics on overflow rather than wrap round.
+            impl Element for $int {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_u64(n: u64) -> Self {
+                    n.into()
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                fn add(&self, other: &Self) -> Self {
+                    self.checked_add(*other).expect("overflow, try BigUint")
+                }
+
+                fn mul(&self, other: &Self) -> Self {
+                    self.checked_mul(*other).expect("overflow, try BigUint")
+                }
+            }
+        )+
+    };
+}
+
+checked_element!(u64, u128);
+
+impl Element for BigUint {
+    fn zero() -> Self {
+        BigUint::ZERO
+    }
+
+    fn one() -> Self {
+        BigUint::from(1u8)
+    }
+
+    fn from_u64(n: u64) -> Self {
+        n.into()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == BigUint::ZERO
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+/// An integer modulo `M`, for answers only wanted up to some modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mod<const M: u64>(pub u64);
+
+impl<const M: u64> Element for Mod<M> {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self(1 % M)
+    }
+
+    fn from_u64(n: u64) -> Self {
+        Self(n % M)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self(((u128::from(self.0) + u128::from(other.0)) % u128::from(M)) as u64)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self((u128::from(self.0) * u128::from(other.0) % u128::from(M)) as u64)
+    }
+}
+
+impl<const M: u64> fmt::Display for Mod<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {M})", self.0)
+    }
+}
+
+/// A dense square matrix stored row by row. Products skip zero entries,
+/// which saves time while the powers of a mostly zero step matrix still are,
+/// but the storage and the worst case stay those of a dense matrix.
+///
+/// ```
+/// use aoc_common::matrix::Matrix;
+///
+/// // (a, b) -> (a + b, a) steps through the Fibonacci numbers
+/// let step = Matrix::from_entries(2, [(0, 0, 1), (0, 1, 1), (1, 0, 1)]);
+/// assert_eq!(step.pow(90).apply(&[1u64, 0]), [4660046610375530309, 2880067194370816120]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    size: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Element> Matrix<T> {
+    /// The `size` by `size` matrix of zeros.
+    pub fn zeros(size: usize) -> Self {
+        Self {
+            size,
+            cells: vec![T::zero(); size * size],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut identity = Self::zeros(size);
+        (0..size).for_each(|i| identity.cells[i * size + i] = T::one());
+        identity
+    }
+
+    /// The matrix with `value` added at every `(row, col)`, zero elsewhere.
+    pub fn from_entries(
+        size: usize,
+        entries: impl IntoIterator<Item = (usize, usize, u64)>,
+    ) -> Self {
+        let mut matrix = Self::zeros(size);
+        for (row, col, value) in entries {
+            let cell = &mut matrix.cells[row * size + col];
+            *cell = cell.add(&T::from_u64(value));
+        }
+        matrix
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[row * self.size + col]
+    }
+
+    /// `self` times `other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.size, other.size, "multiplying matrices of two sizes");
+        let n = self.size;
+        let mut product = Self::zeros(n);
+        for i in 0..n {
+            for k in 0..n {
+                let a = &self.cells[i * n + k];
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..n {
+                    let b = &other.cells[k * n + j];
+                    if !b.is_zero() {
+                        let cell = &mut product.cells[i * n + j];
+                        *cell = cell.add(&a.mul(b));
+                    }
+                }
+            }
+        }
+        product
+    }
+
+    /// `self` multiplied by itself `n` times, by repeated squaring.
+    pub fn pow(&self, mut n: u64) -> Self {
+        let mut power = Self::identity(self.size);
+        let mut square = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                power = power.mul(&square);
+            }
+            n >>= 1;
+            if n > 0 {
+                square = square.mul(&square);
+            }
+        }
+        power
+    }
+
+    /// `self` times the column `v`.
+    pub fn apply(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(
+            self.size,
+            v.len(),
+            "multiplying by a vector of another size"
+        );
+        self.cells
+            .chunks(self.size)
+            .map(|row| {
+                row.iter()
+                    .zip(v)
+                    .filter(|(a, b)| !a.is_zero() && !b.is_zero())
+                    .fold(T::zero(), |sum, (a, b)| sum.add(&a.mul(b)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_pow() {
+        let mut rng = Rng::new(0);
+        for _ in 0..50 {
+            let size = rng.below(5) + 1;
+            let entries = (0..rng.below(12))
+                .map(|_| (rng.below(size), rng.below(size), rng.range(0..=3) as u64))
+                .collect::<Vec<_>>();
+            let m = Matrix::<u128>::from_entries(size, entries);
+            let n = rng.below(8) as u64;
+            let naive = (0..n).fold(Matrix::identity(size), |p, _| p.mul(&m));
+            assert_eq!(m.pow(n), naive);
+        }
+    }
+
+    #[test]
+    fn test_backends() {
+        // (a, b) -> (a + b, a): Fibonacci, F(186) being the last to fit a u128
+        let entries = [(0, 0, 1), (0, 1, 1), (1, 0, 1)];
+        let fib = |n: u64| {
+            *Matrix::<u128>::from_entries(2, entries)
+                .pow(n - 1)
+                .get(0, 0)
+        };
+        assert_eq!(fib(186), 332825110087067562321196029789634457848);
+        assert!(std::panic::catch_unwind(|| fib(187)).is_err());
+
+        let big = Matrix::<BigUint>::from_entries(2, entries).pow(300);
+        let modular = Matrix::<Mod<1_000_000_007>>::from_entries(2, entries).pow(300);
+        let expected = big.get(0, 1) % 1_000_000_007u32;
+        assert_eq!(BigUint::from(modular.get(0, 1).0), expected);
+        assert_eq!(big.get(0, 1).to_string().len(), 63);
+    }
+}
@@ -0,0 +1,180 @@
+//! Remembering the results of a recursive search, one table per search.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/// The results of a function by argument, owned by whoever starts the search
+/// so nothing outlives it or needs clearing between parts.
+///
+/// ```
+/// use aoc_common::memo::Memo;
+///
+/// fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.solve(n, |memo| if n < 2 { n } else { fib(n - 1, memo) + fib(n - 2, memo) })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fib(90, &mut memo), 2880067194370816120);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    results: HashMap<K, V>,
+    /// Keys in the order they were added, kept only when bounded.
+    order: VecDeque<K>,
+    limit: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How well a [`Memo`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Results found already computed.
+    pub hits: u64,
+    /// Results that had to be computed.
+    pub misses: u64,
+    /// Results held now.
+    pub len: usize,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} held",
+            self.hits, self.misses, self.len
+        )
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            results: HashMap::new(),
+            order: VecDeque::new(),
+            limit: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// A memo holding at most `limit` results, forgetting the oldest first.
+    pub fn bounded(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// The result for `key`, computed by `f` unless known already. `f` gets
+    /// the memo back for its own recursive calls.
+    pub fn solve(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// The result for `key` if known, counted as a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        match self.results.get(key) {
+            Some(value) => {
+                self.hits += 1;
+                Some(value.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        let Some(limit) = self.limit else {
+            self.results.insert(key, value);
+            return;
+        };
+        if limit == 0 {
+            return;
+        }
+        if self.results.insert(key.clone(), value).is_none() {
+            self.order.push_back(key);
+        }
+        while self.results.len() > limit {
+            let oldest = self.order.pop_front().unwrap();
+            self.results.remove(&oldest);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            len: self.results.len(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Number of paths down and right through a `rows` by `cols` lattice.
+    fn paths(rows: u64, cols: u64, memo: &mut Memo<(u64, u64), u64>) -> u64 {
+        memo.solve((rows, cols), |memo| {
+            if rows == 0 || cols == 0 {
+                1
+            } else {
+                paths(rows - 1, cols, memo) + paths(rows, cols - 1, memo)
+            }
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(16, 16, &mut memo), 601080390);
+        let stats = memo.stats();
+        // every corner but (0, 0) is reached, the inner ones call twice
+        assert_eq!((stats.misses, stats.len), (17 * 17 - 1, 17 * 17 - 1));
+        assert_eq!(stats.hits + stats.misses, 2 * 16 * 16 + 1);
+        assert_eq!(paths(16, 16, &mut memo), 601080390);
+        assert_eq!(memo.stats().hits, stats.hits + 1);
+    }
+
+    #[test]
+    fn test_bounded() {
+        let mut memo = Memo::bounded(2);
+        for n in 0..5 {
+            assert_eq!(memo.solve(n, |_| n * n), n * n);
+        }
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&4), Some(16));
+        assert_eq!(memo.get(&2), None);
+        // still right when results get forgotten along the way
+        let mut memo = Memo::bounded(3);
+        assert_eq!(paths(10, 10, &mut memo), 184756);
+        assert_eq!(memo.len(), 3);
+        assert_eq!(Memo::<u8, u8>::bounded(0).solve(1, |_| 2), 2);
+    }
+}
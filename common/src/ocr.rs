@@ -0,0 +1,289 @@
+//! Reading the capital letters some puzzles draw with `#` instead of giving
+//! an answer to type.
+
+use crate::grid::Grid;
+
+/// A fixed-pitch font of the puzzles: every glyph is `width` by `height`
+/// pixels, with `spacing` blank columns between two glyphs.
+struct Font {
+    height: usize,
+    width: usize,
+    spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The font of most screens, six pixels high.
+static SMALL: Font = Font {
+    height: 6,
+    width: 4,
+    spacing: 1,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The font of the message in the stars, ten pixels high.
+static LARGE: Font = Font {
+    height: 10,
+    width: 6,
+    spacing: 2,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+impl Font {
+    /// The letter drawn in the `width` columns of `pixels` from `left`,
+    /// columns past the right edge being blank.
+    fn glyph(&self, pixels: &Grid<bool>, top: usize, left: usize) -> Option<char> {
+        let lit =
+            |row: usize, col: usize| left + col < pixels.width() && pixels[(top + row, left + col)];
+        self.glyphs
+            .iter()
+            .find(|(_, rows)| {
+                rows.iter().enumerate().all(|(row, line)| {
+                    line.bytes()
+                        .enumerate()
+                        .all(|(col, b)| (b == b'#') == lit(row, col))
+                })
+            })
+            .map(|&(letter, _)| letter)
+    }
+
+    /// The letters of `pixels` from row `top`, `None` unless every glyph is a
+    /// known letter and the gaps between them are blank.
+    fn read(&self, pixels: &Grid<bool>, top: usize) -> Option<String> {
+        let pitch = self.width + self.spacing;
+        let letters = pixels.width().div_ceil(pitch);
+        let blank = |col: usize| (top..top + self.height).all(|row| !pixels[(row, col)]);
+        let gaps_blank = (0..pixels.width())
+            .filter(|col| col % pitch >= self.width)
+            .all(blank);
+        if !gaps_blank {
+            return None;
+        }
+        (0..letters)
+            .map(|i| self.glyph(pixels, top, i * pitch))
+            .collect()
+    }
+}
+
+/// Read the capital letters drawn by the lit pixels, in either of the fonts
+/// of the puzzles. Blank rows above and below are ignored, the letters start
+/// in the first column. `None` when the pixels are not all letters.
+pub fn read(pixels: &Grid<bool>) -> Option<String> {
+    let inked = |row: usize| pixels.row(row).iter().any(|&lit| lit);
+    let top = (0..pixels.height()).find(|&row| inked(row))?;
+    let bottom = (0..pixels.height()).rfind(|&row| inked(row))?;
+    [&SMALL, &LARGE]
+        .into_iter()
+        .find(|font| font.height == bottom - top + 1)?
+        .read(pixels, top)
+}
+
+/// [`read`] pixels drawn as `#` on `.`, one line per row.
+pub fn read_art(art: &str) -> Option<String> {
+    read(&Grid::parse(art, |c| Some(c == '#')).ok()?)
+}
+
+/// The letters `art` spells, or the art itself when it is not letters. The
+/// samples draw shapes rather than a message, and their answer is the
+/// drawing.
+pub fn read_art_or_keep(art: String) -> String {
+    read_art(&art).unwrap_or(art)
+}
+
+/// `text` drawn as `#` on `.` in the six pixel font, every letter followed
+/// by its gap as on the screens. `None` for a letter the font lacks.
+pub fn render(text: &str) -> Option<String> {
+    let glyphs = text
+        .chars()
+        .map(|c| SMALL.glyphs.iter().find(|(l, _)| *l == c).map(|g| g.1))
+        .collect::<Option<Vec<_>>>()?;
+    let gap = ".".repeat(SMALL.spacing);
+    let rows = (0..SMALL.height)
+        .map(|row| glyphs.iter().map(|g| format!("{}{gap}", g[row])).collect())
+        .collect::<Vec<String>>();
+    Some(rows.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `text` drawn in `font`.
+    fn draw(font: &Font, text: &str) -> String {
+        let gap = ".".repeat(font.spacing);
+        (0..font.height)
+            .map(|row| {
+                text.chars()
+                    .map(|c| font.glyphs.iter().find(|(l, _)| *l == c).unwrap().1[row])
+                    .collect::<Vec<_>>()
+                    .join(&gap)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_read_small() {
+        let screen = "\
+###..####.####.####.#..#.###..####..##..
+#..#.#.......#.#....#.#..#..#.#....#..#.
+#..#.###....#..###..##...###..###..#..#.
+###..#.....#...#....#.#..#..#.#....####.
+#.#..#....#....#....#.#..#..#.#....#..#.
+#..#.#....####.####.#..#.###..#....#..#.";
+        assert_eq!(read_art(screen).as_deref(), Some("RFZEKBFA"));
+        let letters = SMALL.glyphs.iter().map(|(l, _)| *l).collect::<String>();
+        // no trailing gap after the last letter, with blank rows around
+        let art = draw(&SMALL, &letters);
+        let blank = ".".repeat(art.find('\n').unwrap());
+        let art = format!("{blank}\n{art}\n{blank}");
+        assert_eq!(read_art(&art), Some(letters));
+    }
+
+    #[test]
+    fn test_read_large() {
+        let letters = LARGE.glyphs.iter().map(|(l, _)| *l).collect::<String>();
+        assert_eq!(read_art(&draw(&LARGE, &letters)), Some(letters));
+    }
+
+    #[test]
+    fn test_render() {
+        let art = render("HI").unwrap();
+        assert_eq!(art.lines().next(), Some("#..#..###."));
+        assert_eq!(read_art(&art).as_deref(), Some("HI"));
+        assert_eq!(render("HW"), None);
+    }
+
+    #[test]
+    fn test_not_letters() {
+        assert_eq!(read_art("#####\n#...#\n#...#\n#...#\n#####"), None);
+        assert_eq!(read_art("...\n..."), None);
+        // a pixel in the gap between two letters
+        let art = draw(&SMALL, "HI").replacen("#..#..###", "#..##.###", 1);
+        assert_eq!(read_art(&art), None);
+    }
+}
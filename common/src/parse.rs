@@ -0,0 +1,172 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+/// Where and why a puzzle input could not be parsed. Lines and columns start
+/// at 1, columns count characters.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}, column {column}: {message}{}", Found(token))]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty when something is missing.
+    pub token: String,
+    pub message: String,
+}
+
+struct Found<'a>(&'a str);
+
+impl Display for Found<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            write!(f, ", found {:?}", self.0)
+        }
+    }
+}
+
+impl ParseError {
+    /// An error about `token`, a slice of `input`, located by its address.
+    /// A token built elsewhere is looked up by value, and put at the end of
+    /// the input if it does not occur at all.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let addr = token.as_ptr() as usize;
+        let offset = if addr >= start && addr + token.len() <= start + input.len() {
+            addr - start
+        } else {
+            input.find(token).unwrap_or(input.len())
+        };
+        let (line, column) = position(input, offset);
+        Self {
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Something missing right after `token`, a slice of `input`, such as a
+    /// field at the end of a short line.
+    pub fn after(input: &str, token: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &token[token.len()..], message)
+    }
+
+    /// The input stopped before something it needed.
+    pub fn end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Move an error located in `part`, a slice of `input` parsed on its
+    /// own, to where it is in `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let start = Self::at(input, part, "");
+        Self {
+            line: start.line + self.line - 1,
+            column: if self.line == 1 {
+                start.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+// 1-based line and column of a byte offset
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parse `token`, a slice of `input`, with its [`FromStr`] impl. `what`
+/// names the expected value in the error, e.g. `"a number"`.
+pub fn token<T: FromStr>(input: &str, token: &str, what: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, format!("expected {what}")))
+}
+
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    token(input, s, "a number")
+}
+
+/// [`str::split_once`], failing on `s` when `sep` is not in it.
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::at(input, s, format!("expected {sep:?}")))
+}
+
+/// [`str::strip_prefix`], failing on the start of `s` when it does not
+/// start with `prefix`.
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let len = s
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+        ParseError::at(input, &s[..len], format!("expected {prefix:?}"))
+    })
+}
+
+/// The first character of `s` rejected by `valid`, as a slice of `s`.
+pub fn invalid_char(s: &str, valid: impl Fn(char) -> bool) -> Option<&str> {
+    s.char_indices()
+        .find(|(_, c)| !valid(*c))
+        .map(|(i, c)| &s[i..i + c.len_utf8()])
+}
+
+/// The next item of a split of `s`, failing right after `s` when it ran
+/// out. `what` names the missing item.
+pub fn next<'a>(
+    input: &str,
+    s: &str,
+    items: &mut impl Iterator<Item = &'a str>,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    items
+        .next()
+        .ok_or_else(|| ParseError::after(input, s, format!("expected {what}")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let input = "1 2\n3 x4\n5";
+        let line = input.lines().nth(1).unwrap();
+        let e = line
+            .split(' ')
+            .map(|n| number::<u32>(input, n))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((e.line, e.column, e.token.as_str()), (2, 3, "x4"));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: expected a number, found \"x4\""
+        );
+        let e = split_once(input, line, ",").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = ParseError::end(input, "expected a blank line");
+        assert_eq!((e.line, e.column), (3, 2));
+        assert_eq!(e.to_string(), "line 3, column 2: expected a blank line");
+        let e = ParseError::at(input, &String::from("x4"), "unknown");
+        assert_eq!((e.line, e.column), (2, 3));
+        let part = &input[6..];
+        let e = ParseError::at(part, &part[1..], "unknown").within(input, part);
+        assert_eq!((e.line, e.column), (2, 4));
+        let e = ParseError::at(part, &part[3..], "unknown").within(input, part);
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+}
@@ -0,0 +1,328 @@
+//! Integer vectors in the plane and in space, for days that move through
+//! unbounded coordinates rather than over a [`crate::grid::Grid`].
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or offset in the plane, `y` growing upwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point or offset in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// The smallest box holding some points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+/// What [`Bounds`] needs of the points it holds.
+pub trait Vector: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// `1` on every axis.
+    const ONE: Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    /// Whether no coordinate of `self` is above the one of `other`.
+    fn all_le(self, other: Self) -> bool;
+}
+
+impl<P: Vector> Bounds<P> {
+    /// The box of the single point `p`.
+    pub fn new(p: P) -> Self {
+        Self { min: p, max: p }
+    }
+
+    /// The box of `points`, `None` when there are none.
+    pub fn of(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        points.for_each(|p| bounds.include(p));
+        Some(bounds)
+    }
+
+    /// Grow the box to hold `p`.
+    pub fn include(&mut self, p: P) {
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+
+    /// Number of coordinates covered on every axis.
+    pub fn size(&self) -> P {
+        self.max - self.min + P::ONE
+    }
+}
+
+macro_rules! point {
+    ($point:ident, $($axis:ident),+) => {
+        impl $point {
+            pub const ORIGIN: Self = Self { $($axis: 0),+ };
+
+            pub const fn new($($axis: i64),+) -> Self {
+                Self { $($axis),+ }
+            }
+
+            /// Distance to `other` moving along the axes.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$axis - other.$axis).abs())+
+            }
+
+            /// Distance to `other` moving diagonally too, as a king does.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0 $(.max((self.$axis - other.$axis).abs()))+
+            }
+
+            /// The step of at most one on every axis in the direction of
+            /// `self`.
+            pub fn signum(self) -> Self {
+                Self { $($axis: self.$axis.signum()),+ }
+            }
+
+            /// The lowest coordinates of both on every axis.
+            pub fn min(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.min(other.$axis)),+ }
+            }
+
+            /// The highest coordinates of both on every axis.
+            pub fn max(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.max(other.$axis)),+ }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, n: i64) -> Self {
+                Self { $($axis: self.$axis * n),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Vector for $point {
+            const ONE: Self = Self { $($axis: 1),+ };
+
+            fn min(self, other: Self) -> Self {
+                $point::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $point::max(self, other)
+            }
+
+            fn all_le(self, other: Self) -> bool {
+                true $(&& self.$axis <= other.$axis)+
+            }
+        }
+    };
+}
+
+point!(Point2, x, y);
+point!(Point3, x, y, z);
+
+/// A direction in the plane, up being towards higher `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The step of one in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir::Up => Point2::new(0, 1),
+            Dir::Right => Point2::new(1, 0),
+            Dir::Down => Point2::new(0, -1),
+            Dir::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// A rotation of space by quarter turns, as a matrix of `0` and `±1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Self = Self([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// The 24 ways to turn something in space: any axis facing any of the
+    /// six directions, then one of four turns around it. Mirror images are
+    /// left out. The identity comes first.
+    pub fn all() -> [Self; 24] {
+        let mut rotations = [Self::IDENTITY; 24];
+        let mut n = 0;
+        for [a, b, c] in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            for signs in 0..8 {
+                let sign = |bit: usize| if signs >> bit & 1 == 0 { 1 } else { -1 };
+                let mut m = [[0; 3]; 3];
+                m[0][a] = sign(0);
+                m[1][b] = sign(1);
+                m[2][c] = sign(2);
+                let rotation = Self(m);
+                if rotation.determinant() == 1 {
+                    rotations[n] = rotation;
+                    n += 1;
+                }
+            }
+        }
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl Mul<Point3> for Rotation {
+    type Output = Point3;
+
+    fn mul(self, p: Point3) -> Point3 {
+        let [x, y, z] = self.0.map(|row| row[0] * p.x + row[1] * p.y + row[2] * p.z);
+        Point3 { x, y, z }
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Self;
+
+    /// `self` applied after `other`.
+    fn mul(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|j| (0..3).map(|k| self.0[i][k] * other.0[k][j]).sum())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let (a, b) = (Point2::new(1, -2), Point2::new(-3, 4));
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        let mut c = Point3::ORIGIN;
+        c += Point3::new(1, 2, 3);
+        c -= Point3::new(0, 0, 5);
+        assert_eq!(c, Point3::new(1, 2, -2));
+        assert_eq!(c.manhattan(Point3::ORIGIN), 5);
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds::<Point2>::of([]), None);
+        let bounds = Bounds::of([Point2::new(2, 0), Point2::new(-1, 5), Point2::new(0, 3)]);
+        let bounds = bounds.unwrap();
+        assert_eq!(bounds.min, Point2::new(-1, 0));
+        assert_eq!(bounds.max, Point2::new(2, 5));
+        assert_eq!(bounds.size(), Point2::new(4, 6));
+        assert!(bounds.contains(Point2::new(0, 0)));
+        assert!(!bounds.contains(Point2::new(0, 6)));
+    }
+
+    #[test]
+    fn test_dir() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Left.reverse(), Dir::Right);
+        let sum = Dir::ALL.iter().fold(Point2::ORIGIN, |p, d| p + d.offset());
+        assert_eq!(sum, Point2::ORIGIN);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+        let p = Point3::new(1, 2, 3);
+        let mut turned = rotations.map(|r| r * p).to_vec();
+        turned.sort_by_key(|p| (p.x, p.y, p.z));
+        turned.dedup();
+        assert_eq!(turned.len(), 24);
+        // closed under composition, and every one keeps its distance
+        for a in rotations {
+            assert_eq!((a * p).manhattan(Point3::ORIGIN), 6);
+            for b in rotations {
+                assert!(rotations.contains(&(a * b)));
+            }
+        }
+    }
+}
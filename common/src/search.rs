@@ -0,0 +1,196 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path found by a search, from one of the starts to the goal (both
+/// included), with its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Every node reached so far with its best known cost and predecessor.
+struct Tree<N, C> {
+    index: HashMap<N, usize>,
+    nodes: Vec<(N, Option<usize>, C)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Tree<N, C> {
+    fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: vec![],
+        }
+    }
+
+    /// Record `node` as reached from `parent` at `cost`, returning its index
+    /// if that is better than what was known.
+    fn relax(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(e) => {
+                let idx = *e.get();
+                if cost >= self.nodes[idx].2 {
+                    return None;
+                }
+                self.nodes[idx].1 = parent;
+                self.nodes[idx].2 = cost;
+                Some(idx)
+            }
+            Entry::Vacant(e) => {
+                let idx = self.nodes.len();
+                self.nodes.push((e.key().clone(), parent, cost));
+                e.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    fn path(&self, mut idx: usize) -> Path<N, C> {
+        let cost = self.nodes[idx].2;
+        let mut nodes = vec![self.nodes[idx].0.clone()];
+        while let Some(parent) = self.nodes[idx].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            idx = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth first search over unweighted edges, the cost is the number of
+/// steps. Returns the shortest path to the first node accepted by `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut open = VecDeque::new();
+    for start in starts {
+        open.extend(tree.relax(start, None, 0));
+    }
+    while let Some(idx) = open.pop_front() {
+        let (node, _, steps) = tree.nodes[idx].clone();
+        if is_goal(&node) {
+            return Some(tree.path(idx));
+        }
+        for next in neighbors(&node) {
+            // the first visit of a node is always the shortest one
+            if !tree.index.contains_key(&next) {
+                open.extend(tree.relax(next, Some(idx), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path over weighted edges. `neighbors` yields each reachable node
+/// with the cost of the step to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the remaining cost from
+/// a node to the goal. The path is only guaranteed cheapest if the estimate
+/// never exceeds the real cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    let mut open = BinaryHeap::new();
+    for start in starts {
+        if let Some(idx) = tree.relax(start, None, C::default()) {
+            let estimate = heuristic(&tree.nodes[idx].0);
+            open.push(Reverse((estimate, C::default(), idx)));
+        }
+    }
+    while let Some(Reverse((_, cost, idx))) = open.pop() {
+        let node = tree.nodes[idx].0.clone();
+        // a cheaper way to this node was queued after this entry
+        if cost != tree.nodes[idx].2 {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(tree.path(idx));
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if let Some(next_idx) = tree.relax(next, Some(idx), next_cost) {
+                let estimate = next_cost + heuristic(&tree.nodes[next_idx].0);
+                open.push(Reverse((estimate, next_cost, next_idx)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::grid::{Grid, Pos};
+
+    static SAMPLE: &str = "131\n191\n111";
+
+    fn weighted(grid: &Grid<u8>, p: &Pos) -> Vec<(Pos, u32)> {
+        grid.neighbors4(*p).map(|n| (n, grid[n] as u32)).collect()
+    }
+
+    #[test]
+    fn test_weighted_paths() {
+        let grid = Grid::parse_digits(SAMPLE).unwrap();
+        let expected = Path {
+            cost: 4,
+            nodes: vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+        };
+        let path = dijkstra([(0, 0)], |p| weighted(&grid, p), |p| *p == (2, 2));
+        assert_eq!(path.as_ref(), Some(&expected));
+        let manhattan = |p: &Pos| (4 - p.0 - p.1) as u32;
+        let path = astar(
+            [(0, 0)],
+            |p| weighted(&grid, p),
+            manhattan,
+            |p| *p == (2, 2),
+        );
+        assert_eq!(path, Some(expected));
+    }
+
+    #[test]
+    fn test_bfs_multi_source() {
+        let grid = Grid::parse(SAMPLE, |c| Some(c == '9')).unwrap();
+        let open = |p: &Pos| {
+            grid.neighbors4(*p)
+                .filter(|n| !grid[*n])
+                .collect::<Vec<_>>()
+        };
+        let path = bfs([(0, 0), (0, 2)], open, |p| *p == (1, 2)).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, [(0, 2), (1, 2)]);
+        assert_eq!(bfs([(0, 0)], open, |p| *p == (1, 1)), None);
+    }
+}
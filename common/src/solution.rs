@@ -0,0 +1,173 @@
+use std::{
+    fmt::Display,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use tracing::{debug, info_span};
+
+use crate::{input, parse::ParseError};
+
+/// A day's puzzle: how to read its input and how to answer both parts.
+pub trait Solution {
+    /// The input once parsed, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Whether parse already does the work both parts share, so that its
+    /// timing covers some of the solving.
+    const PARSE_SOLVES: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Both answers of a day, rendered for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // multi-line answers (letters drawn on a screen) start on their own line
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            let sep = if answer.contains('\n') { "\n" } else { " " };
+            writeln!(f, "part {part}:{sep}{answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Wall time spent in each stage of a solve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    /// See [`Solution::PARSE_SOLVES`].
+    pub parse_solves: bool,
+}
+
+/// Parse `input` and answer both parts of `S`.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, ParseError> {
+    solve_timed::<S>(input).map(|(answers, _)| answers)
+}
+
+/// A stage of a solve, see [`solve_observed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// [`solve`], timing each stage. Rendering the answers is left out of the
+/// part timings.
+pub fn solve_timed<S: Solution>(input: &str) -> Result<(Answers, Timings), ParseError> {
+    solve_observed::<S>(input, &mut |_| {})
+}
+
+/// [`solve_timed`], calling `on_stage` as each stage starts so the solve can
+/// be watched from another thread. Type erased so the runner can keep every
+/// day in one table.
+pub fn solve_observed<S: Solution>(
+    input: &str,
+    on_stage: &mut dyn FnMut(Stage),
+) -> Result<(Answers, Timings), ParseError> {
+    on_stage(Stage::Parse);
+    let start = Instant::now();
+    let input = info_span!("parse").in_scope(|| S::parse(input))?;
+    let parsed = Instant::now();
+    on_stage(Stage::Part1);
+    let part1 = info_span!("part1").in_scope(|| S::part1(&input));
+    let part1_done = Instant::now();
+    on_stage(Stage::Part2);
+    let part2 = info_span!("part2").in_scope(|| S::part2(&input));
+    let part2_done = Instant::now();
+    let timings = Timings {
+        parse: parsed - start,
+        part1: part1_done - parsed,
+        part2: part2_done - part1_done,
+        parse_solves: S::PARSE_SOLVES,
+    };
+    debug!(?timings.parse, ?timings.part1, ?timings.part2, "solved");
+    let answers = Answers {
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    };
+    Ok((answers, timings))
+}
+
+/// Entry point of a standalone day binary: read the input named on the
+/// command line (see [`input::from_args`]) and print both answers, or why
+/// they could not be computed.
+pub fn run<S: Solution>(manifest_dir: &str) -> ExitCode {
+    let answers = input::from_args(manifest_dir).and_then(|input| Ok(solve::<S>(&input)?));
+    match answers {
+        Ok(answers) => {
+            print!("{answers}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::parse::number;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().map(|l| number(input, l)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> String {
+            input
+                .iter()
+                .map(|n| "#".repeat(*n as usize))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+
+    #[test]
+    fn test_solve_and_display() {
+        let answers = solve::<Sum>("1\n2").unwrap();
+        assert_eq!(answers.part1, "3");
+        assert_eq!(answers.to_string(), "part 1: 3\npart 2:\n#\n##\n");
+        let e = solve::<Sum>("1\n2\nx").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        let mut stages = vec![];
+        solve_observed::<Sum>("1", &mut |stage| stages.push(stage)).unwrap();
+        assert_eq!(stages, [Stage::Parse, Stage::Part1, Stage::Part2]);
+    }
+}
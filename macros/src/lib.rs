@@ -0,0 +1,94 @@
+//! Derives for the day crates, re-exported by `aoc_common`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Pat};
+
+/// Map the variants of an enum to the characters of a puzzle map, see
+/// `aoc_common::grid::Cell`.
+///
+/// Every variant has a `#[cell(...)]` attribute: a unit variant takes a char
+/// literal, a variant holding a single `char` takes a pattern of chars such as
+/// `'a'..='z'` and keeps the char it was parsed from.
+#[proc_macro_derive(Cell, attributes(cell))]
+pub fn derive_cell(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    cell(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn cell(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "Cell can only be derived for enums",
+        ));
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (mut parse, mut symbol, mut expected) = (vec![], vec![], vec![]);
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("cell"))
+            .ok_or_else(|| Error::new_spanned(variant, "expected a #[cell(...)] attribute"))?;
+        let pat = attr.parse_args_with(Pat::parse_multi)?;
+        expected.push(pat.to_token_stream().to_string().replace(' ', ""));
+        match &variant.fields {
+            Fields::Unit => {
+                let Pat::Lit(lit) = &pat else {
+                    return Err(Error::new_spanned(pat, "expected a char literal"));
+                };
+                parse.push(quote!(#lit => Ok(Self::#ident)));
+                symbol.push(quote!(Self::#ident => #lit));
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                parse.push(quote!(c @ (#pat) => Ok(Self::#ident(c))));
+                symbol.push(quote!(Self::#ident(c) => *c));
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    &variant.fields,
+                    "expected no fields or a single char",
+                ))
+            }
+        }
+    }
+    let expected = expected.join(", ");
+    Ok(quote! {
+        impl #impl_generics ::aoc_common::grid::Cell for #name #ty_generics #where_clause {
+            fn symbol(&self) -> char {
+                match self {
+                    #(#symbol,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = ::aoc_common::grid::CellError;
+
+            fn try_from(c: char) -> ::core::result::Result<Self, Self::Error> {
+                match c {
+                    #(#parse,)*
+                    ch => Err(::aoc_common::grid::CellError { ch, expected: #expected }),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for char #where_clause {
+            fn from(cell: #name #ty_generics) -> char {
+                ::aoc_common::grid::Cell::symbol(&cell)
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}", ::aoc_common::grid::Cell::symbol(self))
+            }
+        }
+    })
+}